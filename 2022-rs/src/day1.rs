@@ -0,0 +1,65 @@
+use aoc_core::{Answer, Input, ParseError, Solution};
+
+/// Returns the summed calories per elf. Sorted. Biggest first.
+fn get_calorie_sums(input: &Input) -> Result<Vec<u32>, ParseError> {
+    let mut calorie_sums = input
+        .sections()
+        .iter()
+        .map(|group| group.lines().map(|line| input.parse_at::<u32>(line)).sum())
+        .collect::<Result<Vec<u32>, _>>()?;
+    calorie_sums.sort();
+    calorie_sums.reverse();
+    Ok(calorie_sums)
+}
+
+fn get_biggest_sum(calorie_sums: &[u32]) -> Option<u32> {
+    calorie_sums.first().copied()
+}
+
+/// The sum of the biggest three, `None` if there are fewer than three.
+fn get_biggest_three_sum(calorie_sums: &[u32]) -> Option<u32> {
+    calorie_sums.get(..3).map(|biggest| biggest.iter().sum())
+}
+
+pub struct Day1;
+
+impl Solution for Day1 {
+    type Parsed = Vec<u32>;
+
+    fn parse(input: &Input) -> Result<Self::Parsed, ParseError> {
+        get_calorie_sums(input)
+    }
+
+    fn part_one(calorie_sums: &Self::Parsed) -> Result<Answer, ParseError> {
+        get_biggest_sum(calorie_sums)
+            .map(Answer::from)
+            .ok_or_else(|| ParseError::new("expected a group of calories"))
+    }
+
+    fn part_two(calorie_sums: &Self::Parsed) -> Result<Answer, ParseError> {
+        get_biggest_three_sum(calorie_sums)
+            .map(Answer::from)
+            .ok_or_else(|| {
+                ParseError::new(format!(
+                    "expected at least 3 groups of calories, found {}",
+                    calorie_sums.len()
+                ))
+            })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn too_few_groups_test() {
+        let calorie_sums = Day1::parse(&Input::from_contents("1000\n2000\n\n3000")).unwrap();
+        assert_eq!(Day1::part_one(&calorie_sums).unwrap(), 3000);
+        let err = Day1::part_two(&calorie_sums).unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "expected at least 3 groups of calories, found 2"
+        );
+    }
+}
@@ -1,18 +1,18 @@
 use std::{fmt::Display, str::FromStr};
 
-use advent_of_code::input::Input;
+use aoc_core::{Answer, Input, ParseError, Solution};
 
 use Outcome::*;
 use Shape::*;
 
-#[derive(Debug, PartialEq)]
-enum Shape {
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum Shape {
     Rock,
     Paper,
     Scissors,
 }
 
-enum Outcome {
+pub enum Outcome {
     Draw,
     Lose,
     Win,
@@ -29,7 +29,7 @@ impl Outcome {
 }
 
 #[derive(Debug, PartialEq, Eq)]
-struct ParseOutcomeError;
+pub struct ParseOutcomeError;
 
 impl FromStr for Outcome {
     type Err = ParseOutcomeError;
@@ -85,7 +85,7 @@ impl Shape {
 }
 
 #[derive(Debug, PartialEq, Eq)]
-struct ParseShapeError;
+pub struct ParseShapeError;
 
 impl FromStr for Shape {
     type Err = ParseShapeError;
@@ -108,17 +108,47 @@ impl Display for ParseShapeError {
     }
 }
 
+/// A line of the strategy guide. The first column is the shape the opponent plays, how to read the
+/// second column depends on the interpretation.
+pub struct Round<T> {
+    opponent_shape: Shape,
+    response: T,
+}
+
+#[derive(Debug, PartialEq, Eq)]
+pub struct ParseRoundError;
+
+impl Display for ParseRoundError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "expect round to be an opponent shape and a response separated by a space"
+        )
+    }
+}
+
+impl<T: FromStr> FromStr for Round<T> {
+    type Err = ParseRoundError;
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let (opponent_shape, response) = s.split_once(' ').ok_or(ParseRoundError)?;
+        Ok(Round {
+            opponent_shape: opponent_shape.parse().map_err(|_| ParseRoundError)?,
+            response: response.parse().map_err(|_| ParseRoundError)?,
+        })
+    }
+}
+
 /// Uses the interpretation where both columns represent shapes played.
-fn get_score_method_one(lines: &[String]) -> u32 {
+fn get_score_method_one(rounds: &[Round<Shape>]) -> u32 {
     let mut score = 0;
 
-    for line in lines {
-        let splits = line.split(' ').collect::<Vec<&str>>();
-        let opponent_shape: Shape = splits[0].parse().unwrap();
-        let our_shape: Shape = (splits[1]).parse().unwrap();
+    for round in rounds {
+        let our_shape = round.response;
 
         let shape_score = our_shape.shape_score();
-        let outcome_score = our_shape.play_against(opponent_shape).get_outcome_score();
+        let outcome_score = our_shape
+            .play_against(round.opponent_shape)
+            .get_outcome_score();
 
         score = score + shape_score + outcome_score;
     }
@@ -128,17 +158,16 @@ fn get_score_method_one(lines: &[String]) -> u32 {
 
 /// Uses the interpretation where the first column is the shape played but the second is the
 /// outcome desired.
-fn get_score_method_two(lines: &[String]) -> u32 {
+fn get_score_method_two(rounds: &[Round<Outcome>]) -> u32 {
     let mut score = 0;
 
-    for line in lines {
-        let splits = line.split(' ').collect::<Vec<&str>>();
-        let opponent_shape: Shape = splits[0].parse().unwrap();
-        let desired_outcome: Outcome = splits[1].parse().unwrap();
-        let our_shape = opponent_shape.desired_shape(&desired_outcome);
+    for round in rounds {
+        let our_shape = round.opponent_shape.desired_shape(&round.response);
 
         let shape_score = our_shape.shape_score();
-        let outcome_score = our_shape.play_against(opponent_shape).get_outcome_score();
+        let outcome_score = our_shape
+            .play_against(round.opponent_shape)
+            .get_outcome_score();
 
         score = score + shape_score + outcome_score;
     }
@@ -146,13 +175,23 @@ fn get_score_method_two(lines: &[String]) -> u32 {
     score
 }
 
-fn main() {
-    let input = Input::new(2);
-    let score_one = get_score_method_one(input.lines());
-    println!("answer1: {score_one}");
+pub struct Day2;
+
+impl Solution for Day2 {
+    /// The strategy guide read with either interpretation.
+    type Parsed = (Vec<Round<Shape>>, Vec<Round<Outcome>>);
+
+    fn parse(input: &Input) -> Result<Self::Parsed, ParseError> {
+        Ok((input.parse_lines()?, input.parse_lines()?))
+    }
+
+    fn part_one((rounds, _): &Self::Parsed) -> Result<Answer, ParseError> {
+        Ok(get_score_method_one(rounds).into())
+    }
 
-    let score_two = get_score_method_two(input.lines());
-    println!("answer2: {score_two}");
+    fn part_two((_, rounds): &Self::Parsed) -> Result<Answer, ParseError> {
+        Ok(get_score_method_two(rounds).into())
+    }
 }
 
 #[cfg(test)]
@@ -160,18 +199,11 @@ mod tests {
     use super::*;
 
     #[test]
-    fn get_score_one_test() {
-        let input = Input::new(2);
-        let score_one = get_score_method_one(input.lines());
-
-        assert_eq!(11449, score_one);
-    }
-
-    #[test]
-    fn get_score_two_test() {
-        let input = Input::new(2);
-        let score = get_score_method_two(input.lines());
+    fn parse_round_test() {
+        let input = Input::from_contents("A Y\nB X\nC Q\n");
+        let err = input.parse_lines::<Round<Shape>>().err().unwrap();
 
-        assert_eq!(13187, score);
+        assert_eq!(err.line, 3);
+        assert_eq!(err.text, "C Q");
     }
 }
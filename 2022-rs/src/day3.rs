@@ -0,0 +1,209 @@
+//! Part 1
+//! Each rucksack has two large compartments. All items of a given type are meant to go into exactly one of the two compartments. The Elf that did the packing failed to follow this rule for exactly one item type per rucksack.
+//!
+//! The Elves have made a list of all of the items currently in each rucksack (your puzzle input), but they need your help finding the errors. Every item type is identified by a single lowercase or uppercase letter (that is, a and A refer to different types of items).
+//!
+//! The list of items for each rucksack is given as characters all on a single line. A given rucksack always has the same number of items in each of its two compartments, so the first half of the characters represent items in the first compartment, while the second half of the characters represent items in the second compartment.
+//!
+//! For example, suppose you have the following list of contents from six rucksacks:
+//!
+//! ```txt
+//! vJrwpWtwJgWrhcsFMMfFFhFp
+//! jqHRNqRjqzjGDLGLrsFMfFZSrLrFZsSL
+//! PmmdzqPrVvPwwTWBwg
+//! wMqvLMZHhHMvwLHjbvcjnnSBnvTQFn
+//! ttgJtRGJQctTZtZT
+//! CrZsJsPPZsGzwwsLwLmpwMDw
+//! ```
+//! The first rucksack contains the items vJrwpWtwJgWrhcsFMMfFFhFp, which means its first compartment contains the items vJrwpWtwJgWr, while the second compartment contains the items hcsFMMfFFhFp. The only item type that appears in both compartments is lowercase p.
+//! The second rucksack's compartments contain jqHRNqRjqzjGDLGL and rsFMfFZSrLrFZsSL. The only item type that appears in both compartments is uppercase L.
+//! The third rucksack's compartments contain PmmdzqPrV and vPwwTWBwg; the only common item type is uppercase P.
+//! The fourth rucksack's compartments only share item type v.
+//! The fifth rucksack's compartments only share item type t.
+//! The sixth rucksack's compartments only share item type s.
+//! To help prioritize item rearrangement, every item type can be converted to a priority:
+//!
+//! Lowercase item types a through z have priorities 1 through 26.
+//! Uppercase item types A through Z have priorities 27 through 52.
+//! In the above example, the priority of the item type that appears in both compartments of each rucksack is 16 (p), 38 (L), 42 (P), 22 (v), 20 (t), and 19 (s); the sum of these is 157.
+//!
+//! Find the item type that appears in both compartments of each rucksack. What is the sum of the priorities of those item types?
+//!
+//! Part 2
+//! The only way to tell which item type is the right one is by finding the one item type that is common between all three Elves in each group.
+//!
+//! Every set of three lines in your list corresponds to a single group, but each group can have a different badge item type. So, in the above example, the first group's rucksacks are the first three lines:
+//!
+//! ```txt
+//! vJrwpWtwJgWrhcsFMMfFFhFp
+//! jqHRNqRjqzjGDLGLrsFMfFZSrLrFZsSL
+//! PmmdzqPrVvPwwTWBwg
+//! ```
+//! And the second group's rucksacks are the next three lines:
+//!
+//! ```txt
+//! wMqvLMZHhHMvwLHjbvcjnnSBnvTQFn
+//! ttgJtRGJQctTZtZT
+//! CrZsJsPPZsGzwwsLwLmpwMDw
+//! ```
+//! In the first group, the only item type that appears in all three rucksacks is lowercase r; this must be their badges. In the second group, their badge item type must be Z.
+
+use std::collections::HashSet;
+
+use aoc_core::{Answer, Input, ParseError, Solution};
+
+/// The priority of an item type: `a` to `z` are 1 to 26, `A` to `Z` are 27 to 52.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct AlphabetOrdinal(u8);
+
+impl TryFrom<char> for AlphabetOrdinal {
+    type Error = ();
+
+    fn try_from(c: char) -> Result<Self, Self::Error> {
+        match c {
+            // Encoded as utf8, in decimal 'a' is encoded 97.
+            'a'..='z' => Ok(AlphabetOrdinal(c as u8 - 96)),
+            // encoded as utf8, in decimal 'A' is encoded 65.
+            'A'..='Z' => Ok(AlphabetOrdinal(c as u8 - 65 + 27)),
+            _ => Err(()),
+        }
+    }
+}
+
+impl From<AlphabetOrdinal> for u32 {
+    fn from(alphabet_ordinal: AlphabetOrdinal) -> Self {
+        alphabet_ordinal.0 as u32
+    }
+}
+
+/// The item types in a rucksack, the first compartment's then the second's.
+type Rucksack = Vec<AlphabetOrdinal>;
+
+fn parse_rucksack(input: &Input, line: &str) -> Result<Rucksack, ParseError> {
+    line.char_indices()
+        .map(|(index, c)| {
+            AlphabetOrdinal::try_from(c).map_err(|()| {
+                let item = &line[index..index + c.len_utf8()];
+                input.error_at(
+                    item,
+                    format!("expected an item type, a letter, found `{c}`"),
+                )
+            })
+        })
+        .collect()
+}
+
+fn get_sum_method_one(rucksacks: &[Rucksack]) -> u32 {
+    let mut errors = vec![];
+
+    // Split each rucksack into two halves based on the length of the vector.
+    for rucksack in rucksacks {
+        let (compartment_one, compartment_two) = rucksack.split_at(rucksack.len() / 2);
+        let compartment_two = compartment_two.iter().collect::<HashSet<_>>();
+        if let Some(&item) = compartment_one
+            .iter()
+            .find(|item| compartment_two.contains(item))
+        {
+            errors.push(item);
+        }
+    }
+
+    // Cast the errors to u32 and sum them.
+    errors.into_iter().map(u32::from).sum()
+}
+
+fn get_sum_method_two(rucksacks: &[Rucksack]) -> u32 {
+    // Store matches in a vec.
+    let mut matches = vec![];
+
+    // Split the rucksacks into groups of three.
+    let groups = rucksacks.chunks(3);
+    for group in groups {
+        // Find the item types that are common to all three rucksacks.
+        // Turn the first two rucksacks into HashSet's.
+        let first_items = group[0].iter().collect::<HashSet<_>>();
+        let second_items = group[1].iter().collect::<HashSet<_>>();
+
+        // Now check each item in the third rucksack for matches in both sets.
+        if let Some(&item) = group[2]
+            .iter()
+            .find(|item| first_items.contains(item) && second_items.contains(item))
+        {
+            matches.push(item);
+        }
+    }
+
+    // Cast the matches to u32 and sum them.
+    matches.into_iter().map(u32::from).sum()
+}
+
+pub struct Day3;
+
+impl Solution for Day3 {
+    type Parsed = Vec<Rucksack>;
+
+    fn parse(input: &Input) -> Result<Self::Parsed, ParseError> {
+        input
+            .lines()
+            .map(|line| parse_rucksack(input, line))
+            .collect()
+    }
+
+    fn part_one(rucksacks: &Self::Parsed) -> Result<Answer, ParseError> {
+        Ok(get_sum_method_one(rucksacks).into())
+    }
+
+    fn part_two(rucksacks: &Self::Parsed) -> Result<Answer, ParseError> {
+        // The elves are split into groups of three.
+        if !rucksacks.len().is_multiple_of(3) {
+            return Err(ParseError::new(format!(
+                "expected the rucksacks in groups of 3, found {}",
+                rucksacks.len()
+            )));
+        }
+        Ok(get_sum_method_two(rucksacks).into())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EXAMPLE: &str = "vJrwpWtwJgWrhcsFMMfFFhFp
+jqHRNqRjqzjGDLGLrsFMfFZSrLrFZsSL
+PmmdzqPrVvPwwTWBwg
+wMqvLMZHhHMvwLHjbvcjnnSBnvTQFn
+ttgJtRGJQctTZtZT
+CrZsJsPPZsGzwwsLwLmpwMDw
+";
+
+    #[test]
+    fn sum_method_one_test() {
+        let lines = Day3::parse(&Input::from_contents(EXAMPLE)).unwrap();
+        assert_eq!(Day3::part_one(&lines).unwrap(), 157);
+    }
+
+    #[test]
+    fn sum_method_two_test() {
+        let lines = Day3::parse(&Input::from_contents(EXAMPLE)).unwrap();
+        assert_eq!(Day3::part_two(&lines).unwrap(), 70);
+    }
+
+    #[test]
+    fn malformed_rucksacks_test() {
+        let rucksacks = Day3::parse(&Input::from_contents("ab\ncd\n")).unwrap();
+        assert_eq!(Day3::part_one(&rucksacks).unwrap(), 0);
+        let err = Day3::part_two(&rucksacks).unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "expected the rucksacks in groups of 3, found 2"
+        );
+
+        let input = Input::from_contents("1\n2\n3\n");
+        let err = Day3::parse(&input).err().unwrap();
+        assert_eq!(
+            err.to_string(),
+            "line 1, column 1: expected an item type, a letter, found `1`"
+        );
+    }
+}
@@ -0,0 +1,169 @@
+//! Part 1
+//! ---
+//! Space needs to be cleared before the last supplies can be unloaded from the ships, and so several Elves have been assigned the job of cleaning up sections of the camp. Every section has a unique ID number, and each Elf is assigned a range of section IDs.
+//!
+//! However, as some of the Elves compare their section assignments with each other, they've noticed that many of the assignments overlap. To try to quickly find overlaps and reduce duplicated effort, the Elves pair up and make a big list of the section assignments for each pair (your puzzle input).
+//!
+//! For example, consider the following list of section assignment pairs:
+//!
+//! ```txt
+//! 2-4,6-8
+//! 2-3,4-5
+//! 5-7,7-9
+//! 2-8,3-7
+//! 6-6,4-6
+//! 2-6,4-8
+//! ```
+//! For the first few pairs, this list means:
+//!
+//! Within the first pair of Elves, the first Elf was assigned sections 2-4 (sections 2, 3, and 4), while the second Elf was assigned sections 6-8 (sections 6, 7, 8).
+//! The Elves in the second pair were each assigned two sections.
+//! The Elves in the third pair were each assigned three sections: one got sections 5, 6, and 7, while the other also got 7, plus 8 and 9.
+//! This example list uses single-digit section IDs to make it easier to draw; your actual list might contain larger numbers. Visually, these pairs of section assignments look like this:
+//!
+//! ```txt
+//! .234.....  2-4
+//! .....678.  6-8
+//!
+//! .23......  2-3
+//! ...45....  4-5
+//!
+//! ....567..  5-7
+//! ......789  7-9
+//!
+//! .2345678.  2-8
+//! ..34567..  3-7
+//!
+//! .....6...  6-6
+//! ...456...  4-6
+//!
+//! .23456...  2-6
+//! ...45678.  4-8
+//! ```
+//! Some of the pairs have noticed that one of their assignments fully contains the other. For example, 2-8 fully contains 3-7, and 6-6 is fully contained by 4-6. In pairs where one assignment fully contains the other, one Elf in the pair would be exclusively cleaning sections their partner will already be cleaning, so these seem like the most in need of reconsideration. In this example, there are 2 such pairs.
+//!
+//! In how many assignment pairs does one range fully contain the other?
+//!
+//! Part 2
+//! ---
+//! It seems like there is still quite a bit of duplicate work planned. Instead, the Elves would like to know the number of pairs that overlap at all.
+//!
+//! In the above example, the first two pairs (2-4,6-8 and 2-3,4-5) don't overlap, while the remaining four pairs (5-7,7-9, 2-8,3-7, 6-6,4-6, and 2-6,4-8) do overlap:
+//!
+//! 5-7,7-9 overlaps in a single section, 7.
+//! 2-8,3-7 overlaps all of the sections 3 through 7.
+//! 6-6,4-6 overlaps in a single section, 6.
+//! 2-6,4-8 overlaps in sections 4, 5, and 6.
+//! So, in this example, the number of overlapping assignment pairs is 4.
+//!
+//! In how many assignment pairs do the ranges overlap?
+
+use aoc_core::{literal, unsigned, Answer, Input, Interval, ParseError, Parser, Solution};
+
+/// The sections an elf is assigned to clean. Sections are `u32`s, widened so an assignment that
+/// ends at the last of them still has an end past it.
+type Assignment = Interval<u64>;
+
+fn split_lines_into_range_pairs(
+    input: &Input,
+) -> Result<Vec<(Assignment, Assignment)>, ParseError> {
+    let range = || {
+        (unsigned::<u32>(), literal("-"), unsigned::<u32>())
+            .verify(
+                |(first, _, last)| first <= last,
+                "a range that ends after it starts",
+            )
+            .map(|(first, _, last)| Interval::inclusive(u64::from(first), u64::from(last)))
+    };
+    // Each line is two ranges like `2-4` separated by a `,`.
+    let range_pair = (range(), literal(","), range()).map(|(left, _, right)| (left, right));
+
+    input
+        .lines()
+        .map(|line| input.parse_line(line, &range_pair))
+        .collect()
+}
+
+fn count_subset_pairs(range_pairs: &[(Assignment, Assignment)]) -> usize {
+    range_pairs
+        .iter()
+        .filter(|(left, right)| left.covers(right) || right.covers(left))
+        .count()
+}
+
+fn count_overlapping_pairs(range_pairs: &[(Assignment, Assignment)]) -> usize {
+    range_pairs
+        .iter()
+        .filter(|(left, right)| left.overlaps(right))
+        .count()
+}
+
+pub struct Day4;
+
+impl Solution for Day4 {
+    type Parsed = Vec<(Assignment, Assignment)>;
+
+    fn parse(input: &Input) -> Result<Self::Parsed, ParseError> {
+        split_lines_into_range_pairs(input)
+    }
+
+    /// Count the number of range_pairs which are subsets.
+    fn part_one(range_pairs: &Self::Parsed) -> Result<Answer, ParseError> {
+        Ok(count_subset_pairs(range_pairs).into())
+    }
+
+    fn part_two(range_pairs: &Self::Parsed) -> Result<Answer, ParseError> {
+        Ok(count_overlapping_pairs(range_pairs).into())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EXAMPLE: &str = "2-4,6-8\n2-3,4-5\n5-7,7-9\n2-8,3-7\n6-6,4-6\n2-6,4-8\n";
+
+    #[test]
+    fn subset_count_test() {
+        let range_pairs = split_lines_into_range_pairs(&Input::from_contents(EXAMPLE)).unwrap();
+        let subset_count = count_subset_pairs(&range_pairs);
+        assert_eq!(subset_count, 2);
+    }
+
+    #[test]
+    fn overlap_count_test() {
+        let range_pairs = split_lines_into_range_pairs(&Input::from_contents(EXAMPLE)).unwrap();
+        let overlapping_count = count_overlapping_pairs(&range_pairs);
+        assert_eq!(overlapping_count, 4);
+    }
+
+    #[test]
+    fn malformed_range_test() {
+        let input = Input::from_contents("2-4,6-8\n2-3,4\n");
+        let err = split_lines_into_range_pairs(&input).unwrap_err();
+        assert_eq!(err.to_string(), "line 2, column 6: expected `-`");
+
+        let input = Input::from_contents("4-2,6-8\n");
+        let err = split_lines_into_range_pairs(&input).unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "line 1, column 1: expected a range that ends after it starts"
+        );
+    }
+
+    #[test]
+    fn last_section_test() {
+        let input = Input::from_contents("1-4294967295,4294967295-4294967295\n");
+        let range_pairs = split_lines_into_range_pairs(&input).unwrap();
+        assert_eq!(range_pairs[0].0.len(), u64::from(u32::MAX));
+        assert_eq!(count_subset_pairs(&range_pairs), 1);
+        assert_eq!(count_overlapping_pairs(&range_pairs), 1);
+
+        let input = Input::from_contents("1-4294967296,1-2\n");
+        let err = split_lines_into_range_pairs(&input).unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "line 1, column 3: expected an unsigned integer that fits in a `u32`"
+        );
+    }
+}
@@ -0,0 +1,359 @@
+//! The expedition can depart as soon as the final supplies have been unloaded from the ships. Supplies are stored in stacks of marked crates, but because the needed supplies are buried under many other crates, the crates need to be rearranged.
+//!
+//! The ship has a giant cargo crane capable of moving crates between stacks. To ensure none of the crates get crushed or fall over, the crane operator will rearrange them in a series of carefully-planned steps. After the crates are rearranged, the desired crates will be at the top of each stack.
+//!
+//! The Elves don't want to interrupt the crane operator during this delicate procedure, but they forgot to ask her which crate will end up where, and they want to be ready to unload them as soon as possible so they can embark.
+//!
+//! They do, however, have a drawing of the starting stacks of crates and the rearrangement procedure (your puzzle input). For example:
+//!
+//! ```txt
+//!     [D]    
+//! [N] [C]    
+//! [Z] [M] [P]
+//!  1   2   3
+//! ```
+//!
+//! move 1 from 2 to 1
+//! move 3 from 1 to 3
+//! move 2 from 2 to 1
+//! move 1 from 1 to 2
+//! In this example, there are three stacks of crates. Stack 1 contains two crates: crate Z is on the bottom, and crate N is on top. Stack 2 contains three crates; from bottom to top, they are crates M, C, and D. Finally, stack 3 contains a single crate, P.
+//!
+//! Then, the rearrangement procedure is given. In each step of the procedure, a quantity of crates is moved from one stack to a different stack. In the first step of the above rearrangement procedure, one crate is moved from stack 2 to stack 1, resulting in this configuration:
+//!
+//! ```txt
+//! [D]        
+//! [N] [C]    
+//! [Z] [M] [P]
+//!  1   2   3
+//! ```
+//!
+//! In the second step, three crates are moved from stack 1 to stack 3. Crates are moved one at a time, so the first crate to be moved (D) ends up below the second and third crates:
+//!
+//! ```txt
+//!         [Z]
+//!         [N]
+//!     [C] [D]
+//!     [M] [P]
+//!  1   2   3
+//! ```
+//! Then, both crates are moved from stack 2 to stack 1. Again, because crates are moved one at a time, crate C ends up below crate M:
+//!
+//! ```txt
+//!         [Z]
+//!         [N]
+//! [M]     [D]
+//! [C]     [P]
+//!  1   2   3
+//! ```
+//! Finally, one crate is moved from stack 1 to stack 2:
+//!
+//! ```txt
+//!         [Z]
+//!         [N]
+//!         [D]
+//! [C] [M] [P]
+//!  1   2   3
+//! ```
+//! The Elves just need to know which crate will end up on top of each stack; in this example, the top crates are C in stack 1, M in stack 2, and Z in stack 3, so you should combine these together and give the Elves the message CMZ.
+//!
+//! --- Part Two ---
+//! As you watch the crane operator expertly rearrange the crates, you notice the process isn't following your prediction.
+//!
+//! Some mud was covering the writing on the side of the crane, and you quickly wipe it away. The crane isn't a CrateMover 9000 - it's a CrateMover 9001.
+//!
+//! The CrateMover 9001 is notable for many new and exciting features: air conditioning, leather seats, an extra cup holder, and the ability to pick up and move multiple crates at once.
+//!
+//! Again considering the example above, the crates begin in the same configuration:
+//!
+//! ```txt
+//!     [D]    
+//! [N] [C]    
+//! [Z] [M] [P]
+//!  1   2   3
+//! ```
+//! Moving a single crate from stack 2 to stack 1 behaves the same as before:
+//!
+//! ```txt
+//! [D]        
+//! [N] [C]    
+//! [Z] [M] [P]
+//!  1   2   3
+//! ```
+//! However, the action of moving three crates from stack 1 to stack 3 means that those three moved crates stay in the same order, resulting in this new configuration:
+//!
+//! ```txt
+//!         [D]
+//!         [N]
+//!     [C] [Z]
+//!     [M] [P]
+//!  1   2   3
+//! ```
+//! Next, as both crates are moved from stack 2 to stack 1, they retain their order as well:
+//!
+//! ```txt
+//!         [D]
+//!         [N]
+//! [C]     [Z]
+//! [M]     [P]
+//!  1   2   3
+//! ```
+//! Finally, a single crate is still moved from stack 1 to stack 2, but now it's crate C that gets moved:
+//!
+//! ```txt
+//!         [D]
+//!         [N]
+//!         [Z]
+//! [M] [C] [P]
+//!  1   2   3
+//! ```
+//! In this example, the CrateMover 9001 has put the crates in a totally different order: MCD.
+
+use aoc_core::{literal, unsigned, Answer, Input, ParseError, Parser, Solution};
+
+type Instruction = (u32, u32, u32);
+
+/// The crate in a cell of the drawing, `None` for an empty cell. A cell at the end of a line may
+/// have lost its trailing spaces.
+fn parse_crate(input: &Input, cell: &str) -> Result<Option<char>, ParseError> {
+    match cell.as_bytes() {
+        [b'[', c @ b'A'..=b'Z', b']'] => Ok(Some(char::from(*c))),
+        _ if cell.chars().all(|c| c == ' ') => Ok(None),
+        _ => Err(input.error_at(cell, "expected a crate like `[A]`, or three spaces")),
+    }
+}
+
+fn parse_stacks(input: &Input, text: &str) -> Result<Vec<Vec<char>>, ParseError> {
+    let mut lines = text.lines().collect::<Vec<&str>>();
+
+    // Drop the last line, it only numbers the stacks. Each stack is 3 chars wide with a space
+    // between stacks, so that's the width of the drawing.
+    let numbers = lines.pop().unwrap_or_default();
+    let stack_count = numbers.split_whitespace().count();
+    let drawing_width = (stack_count * 4).saturating_sub(1);
+    if lines.is_empty() {
+        return Err(input.error_at(numbers, "expected crates above the stack numbers"));
+    }
+
+    let mut stacks = vec![Vec::new(); stack_count];
+    for line in lines {
+        // Cells are counted in chars, so slice the line at the offsets of its chars.
+        let offsets = line
+            .char_indices()
+            .map(|(offset, _)| offset)
+            .chain([line.len()])
+            .collect::<Vec<_>>();
+        let chars = offsets.len() - 1;
+        let slice =
+            |from: usize, to: usize| &line[offsets[from.min(chars)]..offsets[to.min(chars)]];
+
+        for (index, stack) in stacks.iter_mut().enumerate() {
+            let start = index * 4;
+            if let Some(c) = parse_crate(input, slice(start, start + 3))? {
+                stack.push(c);
+            }
+            let gap = slice(start + 3, start + 4);
+            if index + 1 < stack_count && gap.chars().any(|c| c != ' ') {
+                return Err(input.error_at(gap, "expected a space between stacks"));
+            }
+        }
+
+        let past = slice(drawing_width, chars);
+        if let Some(index) = past.find(|c| c != ' ') {
+            return Err(input.error_at(&past[index..], "expected nothing past the last stack"));
+        }
+    }
+
+    // The drawing is read top down, so reverse each stack to have its top at the end.
+    for stack in &mut stacks {
+        stack.reverse();
+    }
+
+    Ok(stacks)
+}
+
+fn parse_instructions(
+    input: &Input,
+    text: &str,
+    stacks: &[Vec<char>],
+) -> Result<Vec<Instruction>, ParseError> {
+    let stack_count = stacks.len();
+    let stack = || {
+        unsigned::<u32>().verify(
+            |&stack| (1..=stack_count).contains(&(stack as usize)),
+            format!("a stack from 1 to {stack_count}"),
+        )
+    };
+    let instruction = (
+        literal("move "),
+        unsigned(),
+        literal(" from "),
+        stack(),
+        literal(" to "),
+        stack(),
+    )
+        .map(|(_, count, _, from, _, to)| (count, from, to));
+
+    // Follow how many crates each stack holds, both methods move the same number of them.
+    let mut heights = stacks.iter().map(Vec::len).collect::<Vec<_>>();
+    text.lines()
+        .map(|line| {
+            let (count, from, to) = input.parse_line(line, &instruction)?;
+            let height = heights[from as usize - 1];
+            if count as usize > height {
+                return Err(input.error_at(
+                    line,
+                    format!("can't move {count} crates from stack {from}, it holds {height}"),
+                ));
+            }
+            heights[from as usize - 1] -= count as usize;
+            heights[to as usize - 1] += count as usize;
+            Ok((count, from, to))
+        })
+        .collect()
+}
+
+fn execute_instructions_method_one(stacks: &mut [Vec<char>], instructions: &[Instruction]) {
+    // Move the crates according to the instructions.
+    // The first number is the count to move.
+    // The second is the stack to take from.
+    // The third is the stack to move to.
+    // The stacks are 0-indexed.
+    for (count, from, to) in instructions {
+        for _ in 0..*count {
+            let c = stacks[*from as usize - 1]
+                .pop()
+                .expect("expect parsing to check the stack holds enough crates");
+            stacks[*to as usize - 1].push(c);
+        }
+    }
+}
+
+fn execute_instructions_method_two(stacks: &mut [Vec<char>], instructions: &[Instruction]) {
+    // Move the crates on the stacks according to the instructions.
+    // The first number is the count to move.
+    // The second is the stack to take from.
+    // The third is the stack to move to.
+    // The stacks are 0-indexed.
+    // The crates move in groups.
+    for (count, from, to) in instructions {
+        let from = &mut stacks[*from as usize - 1];
+        let mut group = from.split_off(from.len() - *count as usize);
+        stacks[*to as usize - 1].append(&mut group);
+    }
+}
+
+/// The crates on top of each stack, left to right. Empty stacks have none.
+fn top_crates(stacks: &[Vec<char>]) -> String {
+    stacks.iter().filter_map(|stack| stack.last()).collect()
+}
+
+pub struct Day5;
+
+impl Solution for Day5 {
+    type Parsed = (Vec<Vec<char>>, Vec<Instruction>);
+
+    fn parse(input: &Input) -> Result<Self::Parsed, ParseError> {
+        // The input has the stacks and the instructions separated by an empty line.
+        let [stacks_text, instructions_text] = input.sections_exact()?;
+
+        let stacks = parse_stacks(input, stacks_text)?;
+        let instructions = parse_instructions(input, instructions_text, &stacks)?;
+        Ok((stacks, instructions))
+    }
+
+    fn part_one((stacks, instructions): &Self::Parsed) -> Result<Answer, ParseError> {
+        // Clone the stacks so that we can execute the instructions on a copy.
+        let mut stacks = stacks.clone();
+        execute_instructions_method_one(&mut stacks, instructions);
+        Ok(top_crates(&stacks).into())
+    }
+
+    fn part_two((stacks, instructions): &Self::Parsed) -> Result<Answer, ParseError> {
+        let mut stacks = stacks.clone();
+        execute_instructions_method_two(&mut stacks, instructions);
+        Ok(top_crates(&stacks).into())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::INPUTS;
+
+    #[test]
+    fn example_one_test() {
+        let parsed = Day5::parse(&INPUTS.example(5, 1)).unwrap();
+        assert_eq!(Day5::part_one(&parsed).unwrap(), "CMZ");
+    }
+
+    #[test]
+    fn example_two_test() {
+        let parsed = Day5::parse(&INPUTS.example(5, 1)).unwrap();
+        assert_eq!(Day5::part_two(&parsed).unwrap(), "MCD");
+    }
+
+    #[test]
+    fn stacks_without_trailing_spaces_test() {
+        let input = Input::from_contents("[A]\n[B] [C]\n 1   2\n\nmove 1 from 1 to 2");
+        let parsed = Day5::parse(&input).unwrap();
+        assert_eq!(Day5::part_one(&parsed).unwrap(), "BA");
+    }
+
+    #[test]
+    fn malformed_stacks_test() {
+        let error = |drawing: &str| {
+            let input = Input::from_contents(format!("{drawing}\n\nmove 1 from 1 to 2"));
+            Day5::parse(&input).err().unwrap().to_string()
+        };
+        let expected_crate = "expected a crate like `[A]`, or three spaces";
+
+        assert_eq!(
+            error("xQx\n 1   2"),
+            format!("line 1, column 1: {expected_crate}")
+        );
+        assert_eq!(
+            error("ééé [A]\n 1   2"),
+            format!("line 1, column 1: {expected_crate}")
+        );
+        assert_eq!(
+            error("[A] [a]\n 1   2"),
+            format!("line 1, column 5: {expected_crate}")
+        );
+        assert_eq!(
+            error("[A] [B\n 1   2"),
+            format!("line 1, column 5: {expected_crate}")
+        );
+        assert_eq!(
+            error("[A]x[B]\n 1   2"),
+            "line 1, column 4: expected a space between stacks"
+        );
+        assert_eq!(
+            error("[A] [B] x\n 1   2"),
+            "line 1, column 9: expected nothing past the last stack"
+        );
+    }
+
+    #[test]
+    fn malformed_instructions_test() {
+        let stacks = "[A]    \n[B] [C]\n 1   2 \n\n";
+
+        let input = Input::from_contents(format!("{stacks}move 1 from 2 to 1\nmove 1 from 2"));
+        let err = Day5::parse(&input).err().unwrap();
+        assert_eq!(err.to_string(), "line 6, column 14: expected ` to `");
+
+        let input = Input::from_contents(format!("{stacks}move 1 from 3 to 1"));
+        let err = Day5::parse(&input).err().unwrap();
+        assert_eq!(
+            err.to_string(),
+            "line 5, column 13: expected a stack from 1 to 2"
+        );
+
+        let input = Input::from_contents(format!("{stacks}move 1 from 1 to 2\nmove 3 from 2 to 1"));
+        let err = Day5::parse(&input).err().unwrap();
+        assert_eq!(
+            err.to_string(),
+            "line 6, column 1: can't move 3 crates from stack 2, it holds 2"
+        );
+    }
+}
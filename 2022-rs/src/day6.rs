@@ -0,0 +1,159 @@
+//! Advent of Code Day 6
+//! To be able to communicate with the Elves, the device needs to lock on to their signal. The signal is a series of seemingly-random characters that the device receives one at a time.
+//!
+//! To fix the communication system, you need to add a subroutine to the device that detects a start-of-packet marker in the datastream. In the protocol being used by the Elves, the start of a packet is indicated by a sequence of four characters that are all different.
+//!
+//! The device will send your subroutine a datastream buffer (your puzzle input); your subroutine needs to identify the first position where the four most recently received characters were all different. Specifically, it needs to report the number of characters from the beginning of the buffer to the end of the first such four-character marker.
+//!
+//! For example, suppose you receive the following datastream buffer:
+//!
+//! mjqjpqmgbljsphdztnvjfqwrcgsmlb
+//! After the first three characters (mjq) have been received, there haven't been enough characters received yet to find the marker. The first time a marker could occur is after the fourth character is received, making the most recent four characters mjqj. Because j is repeated, this isn't a marker.
+//!
+//! The first time a marker appears is after the seventh character arrives. Once it does, the last four characters received are jpqm, which are all different. In this case, your subroutine should report the value 7, because the first start-of-packet marker is complete after 7 characters have been processed.
+//!
+//! Here are a few more examples:
+//!
+//! bvwbjplbgvbhsrlpgdmjqwftvncz: first marker after character 5
+//! nppdvjthqldpwncqszvftbrmjlhg: first marker after character 6
+//! nznrnfrfntjfmvfwmzdfjlvtqnbhcprsg: first marker after character 10
+//! zcfzfwzzqfrljwzlrfnpqdbhtmscgvjw: first marker after character 11
+//!
+//! A start-of-message marker is just like a start-of-packet marker, except it consists of 14 distinct characters rather than 4.
+//!
+//! Here are the first positions of start-of-message markers for all of the above examples:
+//!
+//! mjqjpqmgbljsphdztnvjfqwrcgsmlb: first marker after character 19
+//! bvwbjplbgvbhsrlpgdmjqwftvncz: first marker after character 23
+//! nppdvjthqldpwncqszvftbrmjlhg: first marker after character 23
+//! nznrnfrfntjfmvfwmzdfjlvtqnbhcprsg: first marker after character 29
+//! zcfzfwzzqfrljwzlrfnpqdbhtmscgvjw: first marker after character 26
+
+use std::collections::HashSet;
+
+use aoc_core::{Answer, Input, ParseError, Solution};
+
+/// The size of the start-of-packet marker.
+const PACKET_MARKER: usize = 4;
+
+/// The size of the start-of-message marker.
+const MESSAGE_MARKER: usize = 14;
+
+/// Returns the number of characters processed once the first `size` distinct characters in a row
+/// have been received, `None` if they never are.
+fn find_marker(input: &[u8], size: usize) -> Option<usize> {
+    // Slide a window of `size` bytes over the datastream. If a hashset of the window has `size`
+    // elements, we have a marker.
+    let start = input
+        .windows(size)
+        .position(|window| window.iter().collect::<HashSet<_>>().len() == size)?;
+
+    Some(start + size)
+}
+
+/// The markers of a datastream, `None` where the datastream has none.
+pub struct Markers {
+    start_of_packet: Option<usize>,
+    start_of_message: Option<usize>,
+}
+
+fn missing_marker(size: usize) -> ParseError {
+    ParseError::new(format!("expected {size} different characters in a row"))
+}
+
+fn find_start_of_packet(input: &[u8]) -> Option<usize> {
+    find_marker(input, PACKET_MARKER)
+}
+
+fn find_start_of_message(input: &[u8]) -> Option<usize> {
+    find_marker(input, MESSAGE_MARKER)
+}
+
+pub struct Day6;
+
+impl Solution for Day6 {
+    type Parsed = Markers;
+
+    fn parse(input: &Input) -> Result<Self::Parsed, ParseError> {
+        let datastream = input.bytes();
+        Ok(Markers {
+            start_of_packet: find_start_of_packet(datastream),
+            start_of_message: find_start_of_message(datastream),
+        })
+    }
+
+    fn part_one(markers: &Self::Parsed) -> Result<Answer, ParseError> {
+        markers
+            .start_of_packet
+            .map(Answer::from)
+            .ok_or_else(|| missing_marker(PACKET_MARKER))
+    }
+
+    fn part_two(markers: &Self::Parsed) -> Result<Answer, ParseError> {
+        markers
+            .start_of_message
+            .map(Answer::from)
+            .ok_or_else(|| missing_marker(MESSAGE_MARKER))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_day6_1() {
+        assert_eq!(
+            find_start_of_packet(b"mjqjpqmgbljsphdztnvjfqwrcgsmlb"),
+            Some(7)
+        );
+        assert_eq!(
+            find_start_of_packet(b"bvwbjplbgvbhsrlpgdmjqwftvncz"),
+            Some(5)
+        );
+        assert_eq!(
+            find_start_of_packet(b"nppdvjthqldpwncqszvftbrmjlhg"),
+            Some(6)
+        );
+        assert_eq!(
+            find_start_of_packet(b"nznrnfrfntjfmvfwmzdfjlvtqnbhcprsg"),
+            Some(10)
+        );
+        assert_eq!(
+            find_start_of_packet(b"zcfzfwzzqfrljwzlrfnpqdbhtmscgvjw"),
+            Some(11)
+        );
+    }
+
+    #[test]
+    fn test_day6_2() {
+        assert_eq!(
+            find_start_of_message(b"mjqjpqmgbljsphdztnvjfqwrcgsmlb"),
+            Some(19)
+        );
+        assert_eq!(
+            find_start_of_message(b"bvwbjplbgvbhsrlpgdmjqwftvncz"),
+            Some(23)
+        );
+        assert_eq!(
+            find_start_of_message(b"nppdvjthqldpwncqszvftbrmjlhg"),
+            Some(23)
+        );
+        assert_eq!(
+            find_start_of_message(b"nznrnfrfntjfmvfwmzdfjlvtqnbhcprsg"),
+            Some(29)
+        );
+        assert_eq!(
+            find_start_of_message(b"zcfzfwzzqfrljwzlrfnpqdbhtmscgvjw"),
+            Some(26)
+        );
+    }
+
+    #[test]
+    fn missing_marker_test() {
+        let markers = Day6::parse(&Input::from_contents("abcdabcdabcdabcd")).unwrap();
+        assert_eq!(Day6::part_one(&markers).unwrap(), 4);
+        let err = Day6::part_two(&markers).unwrap_err();
+        assert_eq!(err.to_string(), "expected 14 different characters in a row");
+    }
+}
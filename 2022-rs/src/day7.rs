@@ -1,6 +1,6 @@
 //! You browse around the filesystem to assess the situation and save the resulting terminal output (your puzzle input). For example:
 //!
-//! ```
+//! ```txt
 //! $ cd /
 //! $ ls
 //! dir a
@@ -38,7 +38,7 @@
 //! dir xyz means that the current directory contains a directory named xyz.
 //! Given the commands and output in the example above, you can determine that the filesystem looks visually like this:
 //!
-//! ```
+//! ```txt
 //! - / (dir)
 //!   - a (dir)
 //!     - e (dir)
@@ -75,9 +75,17 @@ use std::{
     rc::Rc,
 };
 
-type DirectoryHandle = Rc<RefCell<Directory>>;
+use aoc_core::{Answer, Input, ParseError, Solution};
+
+/// The size of the disk.
+const TOTAL_SPACE: u64 = 70000000;
+
+/// The unused space the update needs.
+const NEEDED_SPACE: u32 = 30000000;
+
+pub type DirectoryHandle = Rc<RefCell<Directory>>;
 
-struct Directory {
+pub struct Directory {
     directories: HashMap<String, DirectoryHandle>,
     files: HashMap<String, u32>,
     name: String,
@@ -149,75 +157,87 @@ impl Debug for Directory {
     }
 }
 
-enum Listing {
-    File(String, u64),
-    Directory(String),
+enum Listing<'a> {
+    File(&'a str, u64),
+    Directory(&'a str),
 }
 
-enum Command {
-    ChangeDirectory(String),
+enum Command<'a> {
+    ChangeDirectory(&'a str),
     ChangeDirectoryUp,
     List,
 }
 
-enum Instruction {
-    Command(Command),
-    Listing(Listing),
+enum Instruction<'a> {
+    Command(Command<'a>),
+    Listing(Listing<'a>),
 }
 
-fn parse_line(line: &str) -> Instruction {
+fn parse_line<'a>(input: &Input, line: &'a str) -> Result<Instruction<'a>, ParseError> {
     let mut parts = line.split_whitespace();
-    let first_part = parts.next().unwrap();
+    let end_of_line = &line[line.len()..];
+    let mut next_part = |expected: &str| {
+        parts
+            .next()
+            .ok_or_else(|| input.error_at(end_of_line, format!("expected {expected}")))
+    };
+
+    let first_part = next_part("a command or a listing")?;
     match first_part {
         "$" => {
-            let command = parts.next().unwrap();
+            let command = next_part("a command")?;
             match command {
                 "cd" => {
-                    let argument = parts.next().unwrap();
+                    let argument = next_part("a directory to change to")?;
                     if argument == ".." {
-                        Instruction::Command(Command::ChangeDirectoryUp)
+                        Ok(Instruction::Command(Command::ChangeDirectoryUp))
                     } else {
-                        Instruction::Command(Command::ChangeDirectory(argument.to_string()))
+                        Ok(Instruction::Command(Command::ChangeDirectory(argument)))
                     }
                 }
-                "ls" => Instruction::Command(Command::List),
-                _ => panic!("Unknown command: {}", command),
+                "ls" => Ok(Instruction::Command(Command::List)),
+                _ => Err(input.error_at(command, format!("unknown command `{command}`"))),
             }
         }
         _ => match first_part {
             "dir" => {
-                let name = parts.next().unwrap();
-                Instruction::Listing(Listing::Directory(name.to_string()))
+                let name = next_part("a directory name")?;
+                Ok(Instruction::Listing(Listing::Directory(name)))
             }
             _ => {
-                let size = first_part.parse::<u64>().unwrap();
-                let name = parts.next().unwrap();
-                Instruction::Listing(Listing::File(name.to_string(), size))
+                let size = input.parse_at::<u64>(first_part)?;
+                let name = next_part("a file name")?;
+                Ok(Instruction::Listing(Listing::File(name, size)))
             }
         },
     }
 }
 
-fn build_tree(input: &str) -> DirectoryHandle {
+fn build_tree(input: &Input) -> Result<DirectoryHandle, ParseError> {
     let root = Directory::new("/", None, 0);
     let root_handle = Rc::new(RefCell::new(root));
 
     let mut current_directory: DirectoryHandle = root_handle.clone();
 
+    // The first line changes to the root directory, which is already the current directory.
+    let mut lines = input.lines();
+    let first_line = lines.next().unwrap_or_default();
+    if first_line != "$ cd /" {
+        return Err(input.error_at(first_line, "expected `$ cd /` first"));
+    }
+
     // Step through each line in the input.
-    // Skips the first line as it changes to the root directory which is already the current directory.
-    for line in input.lines().skip(1) {
+    for line in lines {
         // Parse each line into an instruction or a listing
-        let instruction = parse_line(line);
+        let instruction = parse_line(input, line)?;
         match instruction {
             Instruction::Command(command) => {
                 match command {
                     Command::ChangeDirectoryUp => {
-                        let parent = current_directory
-                            .borrow()
-                            .parent
-                            .clone()
-                            .expect("expect directory we're calling 'up' on to have a parent");
+                        let parent =
+                            current_directory.borrow().parent.clone().ok_or_else(|| {
+                                input.error_at(line, "can't move up from the root directory")
+                            })?;
                         current_directory = parent;
                     }
                     Command::ChangeDirectory(directory_to_change_to) => {
@@ -225,9 +245,14 @@ fn build_tree(input: &str) -> DirectoryHandle {
                         let directory = current_directory
                             .borrow()
                             .directories
-                            .get(&directory_to_change_to)
+                            .get(directory_to_change_to)
                             .cloned()
-                            .expect("expect directory to change to to exist");
+                            .ok_or_else(|| {
+                                input.error_at(
+                                    directory_to_change_to,
+                                    format!("no directory `{directory_to_change_to}` listed here"),
+                                )
+                            })?;
 
                         // Set the current directory to the one we just found or created
                         current_directory = directory.clone();
@@ -239,88 +264,152 @@ fn build_tree(input: &str) -> DirectoryHandle {
             }
             Instruction::Listing(listing) => match listing {
                 Listing::File(name, size) => {
-                    current_directory.borrow_mut().add_file(&name, size as u32);
+                    // A file listed again is only counted once.
+                    if current_directory.borrow().files.contains_key(name) {
+                        continue;
+                    }
+                    let total_size = u64::from(root_handle.borrow().size) + size;
+                    if total_size > TOTAL_SPACE {
+                        return Err(input.error_at(
+                            line,
+                            format!("expected files to fit on a disk of {TOTAL_SPACE}, found {total_size}"),
+                        ));
+                    }
+                    // Fits in a `u32` as the disk does.
+                    current_directory.borrow_mut().add_file(name, size as u32);
                     current_directory.borrow_mut().add_size(size as u32);
                 }
                 Listing::Directory(name) => {
-                    let new_directory = Directory::new(
-                        &name,
-                        Some(current_directory.clone()),
-                        current_directory.borrow().depth + 1,
-                    );
-                    let new_directory_handle = Rc::new(RefCell::new(new_directory));
+                    // A directory listed again keeps what was found in it.
+                    let parent = current_directory.clone();
+                    let depth = parent.borrow().depth + 1;
                     current_directory
                         .borrow_mut()
                         .directories
-                        .insert(name, new_directory_handle.clone());
+                        .entry(name.to_string())
+                        .or_insert_with(|| {
+                            Rc::new(RefCell::new(Directory::new(name, Some(parent), depth)))
+                        });
                 }
             },
         }
     }
 
-    root_handle
+    Ok(root_handle)
 }
 
-fn main() {
-    let input = include_str!("../../input/day7.txt");
-
-    let root_handle = build_tree(input);
+/// All directories in the tree, starting with the root.
+fn all_directories(root_handle: &DirectoryHandle) -> Vec<DirectoryHandle> {
+    let mut dirs = vec![root_handle.clone()];
+    dirs.append(&mut root_handle.borrow().all_child_directories());
+    dirs
+}
 
+fn sum_sizes_under_one_hundred_thousand(root_handle: &DirectoryHandle) -> u32 {
     let mut total_size_under_one_hundred_thousand = 0;
     // Find all directories with a size of at most 100000
-    let mut dirs = vec![];
-    dirs.push(root_handle.clone());
-    let child_dirs = root_handle.borrow().all_child_directories();
-    dirs.append(&mut child_dirs.clone());
-    for dir in dirs {
+    for dir in all_directories(root_handle) {
         if dir.borrow().size <= 100000 {
             total_size_under_one_hundred_thousand += dir.borrow().size;
         }
     }
-    println!(
-        "Total size under 100,000: {}",
-        total_size_under_one_hundred_thousand
-    );
+    total_size_under_one_hundred_thousand
+}
 
+fn find_smallest_directory_to_delete(root_handle: &DirectoryHandle) -> u32 {
+    // Parsing made sure everything fits on the disk, so deleting the root would always do.
     let total_size = root_handle.borrow().size;
-    let total_space = 70000000;
-    let unused_space = total_space - total_size;
-    println!("Unused space: {}", unused_space);
-
-    let needed_space = 30000000;
-    let missing_space = needed_space - unused_space;
-    println!("Missing space: {}", missing_space);
-
-    // Find the smallest directory larger than mising_space.
-    let mut smallest_directory: Option<DirectoryHandle> = None;
-    for dir in child_dirs {
-        if dir.borrow().size >= missing_space {
-            if let Some(smallest) = &smallest_directory {
-                if dir.borrow().size < smallest.borrow().size {
-                    smallest_directory = Some(dir.clone());
-                }
-            } else {
-                smallest_directory = Some(dir.clone());
-            }
-        }
+    let unused_space = TOTAL_SPACE as u32 - total_size;
+    let missing_space = NEEDED_SPACE.saturating_sub(unused_space);
+
+    // Find the smallest directory larger than missing_space.
+    all_directories(root_handle)
+        .iter()
+        .map(|dir| dir.borrow().size)
+        .filter(|&size| size >= missing_space)
+        .min()
+        .unwrap_or(total_size)
+}
+
+pub struct Day7;
+
+impl Solution for Day7 {
+    type Parsed = DirectoryHandle;
+
+    fn parse(input: &Input) -> Result<Self::Parsed, ParseError> {
+        build_tree(input)
+    }
+
+    fn part_one(root_handle: &Self::Parsed) -> Result<Answer, ParseError> {
+        Ok(sum_sizes_under_one_hundred_thousand(root_handle).into())
     }
 
-    println!(
-        "Smallest directory size: {:?}",
-        smallest_directory.unwrap().borrow().size
-    );
+    fn part_two(root_handle: &Self::Parsed) -> Result<Answer, ParseError> {
+        Ok(find_smallest_directory_to_delete(root_handle).into())
+    }
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::INPUTS;
 
     #[test]
     fn example_one_test() {
-        let input = include_str!("../../input/day7-example.txt");
-        let root_handle = build_tree(input);
+        let input = INPUTS.example(7, 1);
+        let root_handle = build_tree(&input).unwrap();
 
         // Assert total size is 48381165
         assert_eq!(root_handle.borrow().size, 48381165);
+        assert_eq!(Day7::part_one(&root_handle).unwrap(), 95437);
+    }
+
+    #[test]
+    fn example_two_test() {
+        let root_handle = Day7::parse(&INPUTS.example(7, 1)).unwrap();
+        assert_eq!(Day7::part_two(&root_handle).unwrap(), 24933642);
+    }
+
+    #[test]
+    fn malformed_terminal_output_test() {
+        let input = Input::from_contents("$ cd /\n$ ls\ndir a\n$ rm a\n");
+        let err = build_tree(&input).err().unwrap();
+        assert_eq!(err.to_string(), "line 4, column 3: unknown command `rm`");
+
+        let input = Input::from_contents("$ cd /\n$ ls\ndir a\n$ cd b\n");
+        let err = build_tree(&input).err().unwrap();
+        assert_eq!(
+            err.to_string(),
+            "line 4, column 6: no directory `b` listed here"
+        );
+
+        let input = Input::from_contents("$ cd /\n$ ls\n12x b.txt\n");
+        let err = build_tree(&input).err().unwrap();
+        assert_eq!(
+            err.to_string(),
+            "line 3, column 1: invalid `12x`: invalid digit found in string"
+        );
+
+        let input = Input::from_contents("$ cd /\n$ ls\n40000000 a\n40000000 b\n");
+        let err = build_tree(&input).err().unwrap();
+        assert_eq!(
+            err.to_string(),
+            "line 4, column 1: expected files to fit on a disk of 70000000, found 80000000"
+        );
+
+        let input = Input::from_contents("$ ls\n");
+        let err = build_tree(&input).err().unwrap();
+        assert_eq!(err.to_string(), "line 1, column 1: expected `$ cd /` first");
+    }
+
+    #[test]
+    fn repeated_listing_test() {
+        let input = Input::from_contents(
+            "$ cd /\n$ ls\ndir a\n10 b\n$ cd a\n$ ls\n5 c\n$ cd ..\n$ ls\ndir a\n10 b\n",
+        );
+        let root_handle = build_tree(&input).unwrap();
+        assert_eq!(root_handle.borrow().size, 15);
+        assert_eq!(root_handle.borrow().directories["a"].borrow().size, 5);
+        assert_eq!(Day7::part_one(&root_handle).unwrap(), 20);
     }
 }
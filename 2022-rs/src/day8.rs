@@ -5,7 +5,7 @@
 //!
 //! The Elves have already launched a quadcopter to generate a map with the height of each tree (your puzzle input). For example:
 //!
-//! ```
+//! ```txt
 //! 30373
 //! 25512
 //! 65332
@@ -29,52 +29,50 @@
 //!
 //! Consider your map; how many trees are visible from outside the grid?
 
-use advent_of_code::input::Input;
+use aoc_core::{Answer, Direction, Grid, Input, ParseError, Solution, Span};
+
+fn parse_input(input: &Input) -> Result<Grid<u8>, ParseError> {
+    input.grid()?.try_map(|point, c| {
+        let (x, y) = <(usize, usize)>::try_from(point).expect("expect grid points in the grid");
+        let span = Span {
+            line: y + 1,
+            column: x + 1,
+            len: 1,
+        };
+        c.to_digit(10)
+            .map(|height| height as u8)
+            .ok_or_else(|| ParseError::at(span, format!("expected a tree height, found `{c}`")))
+    })
+}
 
-fn parse_input(input: &str) -> Vec<Vec<u8>> {
-    input
-        .lines()
-        .map(|line| {
-            line.chars()
-                .map(|c| c.to_digit(10).unwrap() as u8)
-                .collect()
+/// A tree is visible when every tree between it and an edge is shorter, looking along a row or
+/// column. Trees on the edge have nothing between them and it, so they are always visible.
+fn count_visible_trees(map: &Grid<u8>) -> usize {
+    map.iter()
+        .filter(|&(point, tree)| {
+            Direction::ORTHOGONAL
+                .into_iter()
+                .any(|direction| map.ray(point, direction).all(|(_, other)| other < tree))
         })
-        .collect()
+        .count()
 }
 
-fn count_visible_trees(map: &[Vec<u8>]) -> usize {
-    let mut visible_trees = 0;
-    for (y, row) in map.iter().enumerate() {
-        for (x, tree) in row.iter().enumerate() {
-            // Trees on the edge are always visible
-            if x == 0 || y == 0 || x == row.len() - 1 || y == map.len() - 1 {
-                visible_trees += 1;
-                continue;
-            }
+pub struct Day8;
 
-            // Check if the tree is visible from any direction
-            let visible_left = row[..x].iter().all(|t| *t < *tree);
-            let visible_right = row[x + 1..].iter().all(|t| *t < *tree);
-            let visible_up = map[..y].iter().all(|r| r[x] < *tree);
-            let visible_down = map[y + 1..].iter().all(|r| r[x] < *tree);
+impl Solution for Day8 {
+    type Parsed = Grid<u8>;
 
-            // If the tree is visible from any direction, count it
-            if visible_left || visible_right || visible_up || visible_down {
-                visible_trees += 1;
-            }
-        }
+    fn parse(input: &Input) -> Result<Self::Parsed, ParseError> {
+        parse_input(input)
     }
-    visible_trees
-}
 
-pub fn main() {
-    println!("day 8 solutions");
-
-    let input = Input::new(8).raw_string();
-    let map = parse_input(&input);
+    fn part_one(map: &Self::Parsed) -> Result<Answer, ParseError> {
+        Ok(count_visible_trees(map).into())
+    }
 
-    let visible_trees = count_visible_trees(&map);
-    println!("part 1: {}", visible_trees);
+    fn part_two(_map: &Self::Parsed) -> Result<Answer, ParseError> {
+        Ok(Answer::Unsolved)
+    }
 }
 
 #[cfg(test)]
@@ -83,26 +81,35 @@ mod tests {
 
     #[test]
     fn test_parse_input() {
-        let input = "30373\n25512\n65332\n33549\n35390";
-        let expected = vec![
+        let input = Input::from_contents("30373\n25512\n65332\n33549\n35390");
+        let expected = Grid::from_rows([
             vec![3, 0, 3, 7, 3],
             vec![2, 5, 5, 1, 2],
             vec![6, 5, 3, 3, 2],
             vec![3, 3, 5, 4, 9],
             vec![3, 5, 3, 9, 0],
-        ];
-        assert_eq!(parse_input(input), expected);
+        ])
+        .unwrap();
+        assert_eq!(parse_input(&input).unwrap(), expected);
+
+        let input = Input::from_contents("303\n2x5\n");
+        let err = parse_input(&input).unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "line 2, column 2: expected a tree height, found `x`"
+        );
+
+        let input = Input::from_contents("303\n25\n");
+        let err = parse_input(&input).unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "line 2, column 1: grid row 2 is 2 wide, expected 3"
+        );
     }
 
     #[test]
     fn test_count_visible_trees() {
-        let map = vec![
-            vec![3, 0, 3, 7, 3],
-            vec![2, 5, 5, 1, 2],
-            vec![6, 5, 3, 3, 2],
-            vec![3, 3, 5, 4, 9],
-            vec![3, 5, 3, 9, 0],
-        ];
-        assert_eq!(count_visible_trees(&map), 21);
+        let map = Input::from_contents("30373\n25512\n65332\n33549\n35390");
+        assert_eq!(count_visible_trees(&parse_input(&map).unwrap()), 21);
     }
 }
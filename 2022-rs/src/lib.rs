@@ -1 +1,51 @@
-pub mod input;
+//! Advent of Code 2022.
+
+use aoc_core::{InputLoader, Registry};
+
+pub use aoc_core::{Input, InputError};
+
+pub mod day1;
+pub mod day2;
+pub mod day3;
+pub mod day4;
+pub mod day5;
+pub mod day6;
+pub mod day7;
+pub mod day8;
+
+pub const YEAR: u16 = 2022;
+
+/// Loads the inputs of this year from the `input` directory of this crate.
+pub const INPUTS: InputLoader = InputLoader::new(YEAR, env!("CARGO_MANIFEST_DIR"));
+
+/// Register the solution of every day of this year.
+pub fn register(registry: &mut Registry) {
+    registry.register::<day1::Day1>(&INPUTS, 1);
+    registry.register::<day2::Day2>(&INPUTS, 2);
+    registry.register::<day3::Day3>(&INPUTS, 3);
+    registry.register::<day4::Day4>(&INPUTS, 4);
+    registry.register::<day5::Day5>(&INPUTS, 5);
+    registry.register::<day6::Day6>(&INPUTS, 6);
+    registry.register::<day7::Day7>(&INPUTS, 7);
+    registry.register::<day8::Day8>(&INPUTS, 8);
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use aoc_core::assert_answers;
+
+    #[test]
+    fn examples_test() {
+        assert_eq!(INPUTS.examples(5).unwrap(), [1]);
+        assert_eq!(INPUTS.examples(7).unwrap(), [1]);
+        assert_eq!(INPUTS.example(5, 1).lines().next(), Some("    [D]    "));
+    }
+
+    #[test]
+    fn answers_test() {
+        let mut registry = Registry::new();
+        register(&mut registry);
+        assert_answers(&registry);
+    }
+}
@@ -0,0 +1,216 @@
+//! advent of code day 1
+//!
+//! part 1:
+//! Given a list of strings, find the first and last integer in each string, concatenate them, and sum them all up.
+//!
+//! part 2:
+//! Given a list of strings, find the first and last integer in each string, concatenate them, and sum them all up.
+//! This time, integers may be spelled out, e.g. "one" instead of "1".
+
+use aoc_core::{Answer, Input, ParseError, Solution, Span};
+
+const DIGITS_AND_SPELLED_OUT_INTS: [(&str, u32); 18] = [
+    ("1", 1),
+    ("2", 2),
+    ("3", 3),
+    ("4", 4),
+    ("5", 5),
+    ("6", 6),
+    ("7", 7),
+    ("8", 8),
+    ("9", 9),
+    ("one", 1),
+    ("two", 2),
+    ("three", 3),
+    ("four", 4),
+    ("five", 5),
+    ("six", 6),
+    ("seven", 7),
+    ("eight", 8),
+    ("nine", 9),
+];
+
+// walks through the string, looking for integers, and recording them in an vec that
+fn find_first_and_last_digit(s: &str) -> Option<(u32, u32)> {
+    let first = s.chars().find_map(|c| c.to_digit(10))?;
+    let last = s.chars().rev().find_map(|c| c.to_digit(10))?;
+    Some((first, last))
+}
+
+// looks for all substring matches of spelled out integers and integers in the string and records
+// them in order as their integer values.
+fn find_ints_and_spelled_out_ints(s: &str) -> Option<(u32, u32)> {
+    let first = DIGITS_AND_SPELLED_OUT_INTS
+        .iter()
+        .filter_map(|(pattern, value)| s.find(pattern).map(|index| (index, *value)))
+        .min_by_key(|(index, _)| *index)
+        .map(|(_, value)| value)?;
+
+    let last = DIGITS_AND_SPELLED_OUT_INTS
+        .iter()
+        .filter_map(|(pattern, value)| s.rfind(pattern).map(|index| (index, *value)))
+        .max_by_key(|(index, _)| *index)
+        .map(|(_, value)| value)?;
+
+    Some((first, last))
+}
+
+// Concatenates the first and last integer in a pair.
+// For example, if the first integer is 1 and the last integer is 2, the result is 12.
+fn concat_int_pair((first, last): (u32, u32)) -> u32 {
+    first * 10 + last
+}
+
+/// The first and last digit of a line of the calibration document, once counting only digits and
+/// once counting spelled out digits too. A line may spell out all of its digits, so only the
+/// latter is sure to exist.
+pub struct Calibration {
+    digits: Option<(u32, u32)>,
+    digits_and_words: (u32, u32),
+    line: Option<Span>,
+}
+
+pub struct Day1;
+
+impl Solution for Day1 {
+    type Parsed = Vec<Calibration>;
+
+    fn parse(input: &Input) -> Result<Self::Parsed, ParseError> {
+        input
+            .lines()
+            .map(|line| {
+                let digits_and_words = find_ints_and_spelled_out_ints(line).ok_or_else(|| {
+                    input.error_at(line, "expected at least one digit, or one spelled out")
+                })?;
+                Ok(Calibration {
+                    digits: find_first_and_last_digit(line),
+                    digits_and_words,
+                    line: input.span_of(line),
+                })
+            })
+            .collect()
+    }
+
+    fn part_one(calibrations: &Self::Parsed) -> Result<Answer, ParseError> {
+        let sum = calibrations
+            .iter()
+            .map(|calibration| {
+                calibration
+                    .digits
+                    .map(concat_int_pair)
+                    .ok_or_else(|| ParseError {
+                        message: "expected at least one digit".to_string(),
+                        span: calibration.line,
+                    })
+            })
+            .sum::<Result<u32, _>>()?;
+        Ok(sum.into())
+    }
+
+    fn part_two(calibrations: &Self::Parsed) -> Result<Answer, ParseError> {
+        let sum = calibrations
+            .iter()
+            .map(|calibration| concat_int_pair(calibration.digits_and_words))
+            .sum::<u32>();
+        Ok(sum.into())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use indoc::indoc;
+
+    #[test]
+    fn test_example_1() {
+        let input = ["1abc2", "pqr3stu8vwx", "a1b2c3d4e5f", "treb7uchet"];
+        let sum = input
+            .into_iter()
+            .map(|line| find_first_and_last_digit(line).unwrap())
+            .map(concat_int_pair)
+            .sum::<u32>();
+        assert_eq!(sum, 142);
+    }
+
+    #[test]
+    fn test_example_2() {
+        let input = [
+            "two1nine",
+            "eightwothree",
+            "abcone2threexyz",
+            "xtwone3four",
+            "4nineeightseven2",
+            "zoneight234",
+            "7pqrstsixteen",
+        ];
+        let sum = input
+            .into_iter()
+            .map(|line| find_ints_and_spelled_out_ints(line).unwrap())
+            .map(concat_int_pair)
+            .sum::<u32>();
+        assert_eq!(sum, 281);
+    }
+
+    #[test]
+    fn test_eighthree() {
+        let pair = find_ints_and_spelled_out_ints("eighthree").unwrap();
+        let value = concat_int_pair(pair);
+        assert_eq!(value, 83);
+    }
+
+    #[test]
+    fn test_sevenine() {
+        let pair = find_ints_and_spelled_out_ints("sevenine").unwrap();
+        let value = concat_int_pair(pair);
+        assert_eq!(value, 79);
+    }
+
+    #[test]
+    fn test_nineight() {
+        let pair = find_ints_and_spelled_out_ints("nineight").unwrap();
+        let value = concat_int_pair(pair);
+        assert_eq!(value, 98);
+    }
+
+    #[test]
+    fn test_one_spelled_out() {
+        let pair = find_ints_and_spelled_out_ints("one").unwrap();
+        let value = concat_int_pair(pair);
+        assert_eq!(value, 11);
+    }
+
+    #[test]
+    fn test_two_spelled_out_with_middle() {
+        let pair = find_ints_and_spelled_out_ints("two3two").unwrap();
+        let value = concat_int_pair(pair);
+        assert_eq!(value, 22);
+    }
+
+    #[test]
+    fn test_spelled_out_digits_only() {
+        let input = Input::from_contents(indoc! {"
+            two1nine
+            eightwothree
+            abcone2threexyz
+            xtwone3four
+            4nineeightseven2
+            zoneight234
+            7pqrstsixteen
+        "});
+        let calibrations = Day1::parse(&input).unwrap();
+        assert_eq!(Day1::part_two(&calibrations).unwrap(), 281);
+        let err = Day1::part_one(&calibrations).unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "line 2, column 1: expected at least one digit"
+        );
+
+        let err = Day1::parse(&Input::from_contents("1abc2\nabc\n"))
+            .err()
+            .unwrap();
+        assert_eq!(
+            err.to_string(),
+            "line 2, column 1: expected at least one digit, or one spelled out"
+        );
+    }
+}
@@ -0,0 +1,169 @@
+//! Day 2: Cube Conundrum
+
+use aoc_core::{ident, literal, separated, unsigned, Answer, Input, ParseError, Parser, Solution};
+
+#[derive(Debug, Default)]
+struct RevealedSet {
+    red: u32,
+    green: u32,
+    blue: u32,
+}
+
+#[derive(Debug)]
+struct MaxCubeConstraint {
+    red: u32,
+    green: u32,
+    blue: u32,
+}
+
+pub struct Game {
+    id: u32,
+    revealed_sets: Vec<RevealedSet>,
+}
+
+impl Game {
+    fn is_possible(&self, max_cube_constraint: &MaxCubeConstraint) -> bool {
+        self.revealed_sets.iter().all(|revealed_set| {
+            revealed_set.red <= max_cube_constraint.red
+                && revealed_set.green <= max_cube_constraint.green
+                && revealed_set.blue <= max_cube_constraint.blue
+        })
+    }
+
+    fn max_color(&self, color: &str) -> u32 {
+        self.revealed_sets
+            .iter()
+            .map(|revealed_set| match color {
+                "red" => revealed_set.red,
+                "green" => revealed_set.green,
+                "blue" => revealed_set.blue,
+                _ => 0,
+            })
+            .max()
+            .unwrap_or(0)
+    }
+
+    fn calc_power(&self) -> u32 {
+        self.max_color("red") * self.max_color("green") * self.max_color("blue")
+    }
+}
+
+fn parse_game(input: &Input, line: &str) -> Result<Game, ParseError> {
+    // `Game <id>: <count> <color>, ...; ...`
+    let cubes = (unsigned::<u32>(), literal(" "), ident()).map(|(count, _, color)| (count, color));
+    let game = (
+        literal("Game "),
+        unsigned(),
+        literal(": "),
+        separated(separated(cubes, literal(", ")), literal("; ")),
+    );
+
+    let (_, id, _, revealed_sets) = input.parse_line(line, &game)?;
+    Ok(Game {
+        id,
+        revealed_sets: revealed_sets
+            .into_iter()
+            .map(|cubes| parse_revealed_set(input, &cubes))
+            .collect::<Result<_, _>>()?,
+    })
+}
+
+fn parse_revealed_set(input: &Input, cubes: &[(u32, &str)]) -> Result<RevealedSet, ParseError> {
+    let mut set = RevealedSet::default();
+    for &(count, color) in cubes {
+        match color {
+            "red" => set.red = count,
+            "green" => set.green = count,
+            "blue" => set.blue = count,
+            _ => return Err(input.error_at(color, format!("unknown color `{color}`"))),
+        }
+    }
+    Ok(set)
+}
+
+fn solution1(games: &[Game], max_cube_constraint: &MaxCubeConstraint) -> u32 {
+    games
+        .iter()
+        .filter(|game| game.is_possible(max_cube_constraint))
+        .map(|game| game.id)
+        .sum()
+}
+
+fn solution2(games: &[Game]) -> u32 {
+    games.iter().map(Game::calc_power).sum()
+}
+
+/// The bag the elf asks about in part 1.
+const MAX_CUBE_CONSTRAINT: MaxCubeConstraint = MaxCubeConstraint {
+    red: 12,
+    green: 13,
+    blue: 14,
+};
+
+pub struct Day2;
+
+impl Solution for Day2 {
+    type Parsed = Vec<Game>;
+
+    fn parse(input: &Input) -> Result<Self::Parsed, ParseError> {
+        input.lines().map(|line| parse_game(input, line)).collect()
+    }
+
+    fn part_one(games: &Self::Parsed) -> Result<Answer, ParseError> {
+        Ok(solution1(games, &MAX_CUBE_CONSTRAINT).into())
+    }
+
+    fn part_two(games: &Self::Parsed) -> Result<Answer, ParseError> {
+        Ok(solution2(games).into())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use indoc::indoc;
+
+    #[test]
+    fn test_example_1() {
+        let input = Input::from_contents(indoc! {"
+            Game 1: 3 blue, 4 red; 1 red, 2 green, 6 blue; 2 green
+            Game 2: 1 blue, 2 green; 3 green, 4 blue, 1 red; 1 green, 1 blue
+            Game 3: 8 green, 6 blue, 20 red; 5 blue, 4 red, 13 green; 5 green, 1 red
+            Game 4: 1 green, 3 red, 6 blue; 3 green, 6 red; 3 green, 15 blue, 14 red
+            Game 5: 6 red, 1 blue, 3 green; 2 blue, 1 red, 2 green
+        "});
+        let games = Day2::parse(&input).unwrap();
+
+        let max_cube_constraint = MaxCubeConstraint {
+            red: 12,
+            green: 13,
+            blue: 14,
+        };
+
+        let solution = solution1(&games, &max_cube_constraint);
+
+        assert_eq!(solution, 8);
+    }
+
+    #[test]
+    fn test_unknown_color() {
+        let input = Input::from_contents(indoc! {"
+            Game 1: 3 blue, 4 red; 1 red, 2 green
+            Game 2: 1 blue, 2 purple
+        "});
+        let err = Day2::parse(&input).err().unwrap();
+
+        assert_eq!(err.to_string(), "line 2, column 19: unknown color `purple`");
+    }
+
+    #[test]
+    fn test_malformed_game() {
+        let input = Input::from_contents("Game 1: 3 blue; red\n");
+        let err = Day2::parse(&input).err().unwrap();
+
+        assert_eq!(
+            err.to_string(),
+            "line 1, column 17: expected an unsigned integer"
+        );
+    }
+}
@@ -0,0 +1,227 @@
+use std::collections::{HashMap, HashSet};
+
+use aoc_core::{Answer, Grid, Input, ParseError, Point, Solution, Span};
+
+/// A number in the schematic and the points of its digits.
+#[derive(Debug)]
+struct Number {
+    value: u64,
+    points: Vec<Point>,
+}
+
+/// The engine schematic and the numbers in it.
+pub struct Schematic {
+    grid: Grid<char>,
+    numbers: Vec<Number>,
+}
+
+/// Find the points of the digits of every number, reading each row left to right.
+fn find_number_points(grid: &Grid<char>) -> Vec<Vec<Point>> {
+    let mut numbers = Vec::new();
+    let mut current: Option<Vec<Point>> = None;
+
+    for (point, c) in grid.iter() {
+        // A number ends at a non-digit or at the end of its row.
+        if point.x == 0 {
+            numbers.extend(current.take());
+        }
+        match (c.is_ascii_digit(), &mut current) {
+            (true, Some(points)) => points.push(point),
+            (true, None) => current = Some(vec![point]),
+            (false, _) => numbers.extend(current.take()),
+        }
+    }
+    numbers.extend(current);
+
+    numbers
+}
+
+/// Find every number, pointing at the first that doesn't fit in a `u64`.
+fn find_numbers(grid: &Grid<char>) -> Result<Vec<Number>, ParseError> {
+    find_number_points(grid)
+        .into_iter()
+        .map(|points| {
+            let value = points.iter().try_fold(0_u64, |value, &point| {
+                let digit = grid[point].to_digit(10)?;
+                value.checked_mul(10)?.checked_add(digit.into())
+            });
+            let Some(value) = value else {
+                let (x, y) =
+                    <(usize, usize)>::try_from(points[0]).expect("expect grid points in the grid");
+                let span = Span {
+                    line: y + 1,
+                    column: x + 1,
+                    len: points.len(),
+                };
+                return Err(ParseError::at(
+                    span,
+                    "expected a number that fits in a `u64`",
+                ));
+            };
+            Ok(Number { value, points })
+        })
+        .collect()
+}
+
+fn is_symbol(c: char) -> bool {
+    c != '.' && !c.is_ascii_digit()
+}
+
+/// Find all part numbers in a line.
+/// Example line: `467..114..`
+///               `...*......`
+/// Only numbers with a symbol that is not a dot, to its left, top, right, or bottom are part numbers.
+/// Part numbers are separated by dots. Here we would only have one part number `467`.
+///
+/// To avoid doing the same work over and over we break the problem into two parts. First we parse
+/// every number, and record the coordinate of their digits.
+/// Second we walk through our list of numbers and check whether any cell around any of their digits
+/// is a non-dot symbol.
+fn find_part_numbers(Schematic { grid, numbers }: &Schematic) -> impl Iterator<Item = u64> + '_ {
+    numbers
+        .iter()
+        .filter(|number| {
+            number
+                .points
+                .iter()
+                .any(|&point| grid.neighbors8(point).any(|(_, &c)| is_symbol(c)))
+        })
+        .map(|number| number.value)
+}
+
+/// Returns `None` if the sum overflows.
+fn find_part_number_sum(schematic: &Schematic) -> Option<Answer> {
+    Answer::checked_sum(find_part_numbers(schematic))
+}
+
+/// Find the sum of the products of all gear ratios.
+/// Gear ratios are two numbers that share a `*` symbol.
+/// Returns `None` if the sum overflows.
+fn find_gear_ratio_sum(Schematic { grid, numbers }: &Schematic) -> Option<Answer> {
+    let point_to_number: HashMap<Point, usize> = numbers
+        .iter()
+        .enumerate()
+        .flat_map(|(index, number)| number.points.iter().map(move |&point| (point, index)))
+        .collect();
+
+    let mut total = Answer::from(0);
+
+    for (gear, _) in grid.iter().filter(|&(_, &c)| c == '*') {
+        // For each gear, find all adjacent numbers (including diagonals)
+        let adjacent_numbers: HashSet<usize> = grid
+            .neighbors8(gear)
+            .filter_map(|(point, _)| point_to_number.get(&point).copied())
+            .collect();
+
+        if adjacent_numbers.len() == 2 {
+            let ratio = Answer::checked_product(
+                adjacent_numbers
+                    .into_iter()
+                    .map(|index| numbers[index].value),
+            )?;
+            total = total.checked_add(ratio)?;
+        }
+    }
+
+    Some(total)
+}
+
+pub struct Day3;
+
+impl Solution for Day3 {
+    type Parsed = Schematic;
+
+    fn parse(input: &Input) -> Result<Self::Parsed, ParseError> {
+        let grid = input.grid()?;
+        let numbers = find_numbers(&grid)?;
+        Ok(Schematic { grid, numbers })
+    }
+
+    fn part_one(schematic: &Self::Parsed) -> Result<Answer, ParseError> {
+        find_part_number_sum(schematic)
+            .ok_or_else(|| ParseError::new("the sum of the part numbers overflows"))
+    }
+
+    fn part_two(schematic: &Self::Parsed) -> Result<Answer, ParseError> {
+        find_gear_ratio_sum(schematic)
+            .ok_or_else(|| ParseError::new("the sum of the gear ratios overflows"))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::INPUTS;
+    use indoc::indoc;
+
+    #[test]
+    fn test_example1() {
+        let schematic = Day3::parse(&INPUTS.example(3, 1)).unwrap();
+        let solution = find_part_number_sum(&schematic).unwrap();
+        assert_eq!(solution, 4361);
+    }
+
+    #[test]
+    fn test_with_diagonals() {
+        let schematic = Day3::parse(&Input::from_contents(indoc! {"
+           ..1..
+           .#...
+           .....
+        "}))
+        .unwrap();
+        let solution = find_part_number_sum(&schematic).unwrap();
+        assert_eq!(solution, 1);
+    }
+
+    #[test]
+    fn test_partial_input_1() {
+        let schematic = Day3::parse(&Input::from_contents(indoc! {"
+        ...............................930...................................283................
+        ....=.........370...........................48..456......424...-.341*.....554...*807.571
+        ..159.........../..........539*.....73......-...*.......+....954.........*.....7.......*
+        "}))
+        .unwrap();
+        let solution = find_part_number_sum(&schematic).unwrap();
+        assert_eq!(solution, 5513);
+    }
+
+    #[test]
+    fn test_example2() {
+        let schematic = Day3::parse(&INPUTS.example(3, 1)).unwrap();
+        let solution = find_gear_ratio_sum(&schematic).unwrap();
+        assert_eq!(solution, 467835);
+    }
+
+    #[test]
+    fn test_multi_cell_adjacent() {
+        let schematic = Day3::parse(&Input::from_contents(indoc! {"
+            12.
+            *12
+        "}))
+        .unwrap();
+        let solution = find_gear_ratio_sum(&schematic).unwrap();
+        assert_eq!(solution, 144);
+    }
+
+    #[test]
+    fn test_number_too_big() {
+        let input = Input::from_contents(indoc! {"
+            ..12345678901234567890123*
+            1.........................
+        "});
+        let err = Day3::parse(&input).err().unwrap();
+        assert_eq!(
+            err.to_string(),
+            "line 1, column 3: expected a number that fits in a `u64`"
+        );
+
+        let schematic = Day3::parse(&Input::from_contents(indoc! {"
+            12345678901234567890*
+            .......*.....*......1
+            12345678901234567890.
+        "}))
+        .unwrap();
+        let err = Day3::part_two(&schematic).unwrap_err();
+        assert_eq!(err.to_string(), "the sum of the gear ratios overflows");
+    }
+}
@@ -1,3 +1,40 @@
-mod input;
+//! Advent of Code 2023.
 
-pub use input::Input;
+use aoc_core::{InputLoader, Registry};
+
+pub use aoc_core::{Input, InputError};
+
+pub mod day1;
+pub mod day2;
+pub mod day3;
+
+pub const YEAR: u16 = 2023;
+
+/// Loads the inputs of this year from the `input` directory of this crate.
+pub const INPUTS: InputLoader = InputLoader::new(YEAR, env!("CARGO_MANIFEST_DIR"));
+
+/// Register the solution of every day of this year.
+pub fn register(registry: &mut Registry) {
+    registry.register::<day1::Day1>(&INPUTS, 1);
+    registry.register::<day2::Day2>(&INPUTS, 2);
+    registry.register::<day3::Day3>(&INPUTS, 3);
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use aoc_core::assert_answers;
+
+    #[test]
+    fn test_examples() {
+        assert_eq!(INPUTS.examples(3).unwrap(), [1]);
+        assert!(INPUTS.examples(1).unwrap().is_empty());
+    }
+
+    #[test]
+    fn test_answers() {
+        let mut registry = Registry::new();
+        register(&mut registry);
+        assert_answers(&registry);
+    }
+}
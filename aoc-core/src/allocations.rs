@@ -0,0 +1,171 @@
+//! Counting what solutions allocate.
+//!
+//! Install [`CountingAllocator`] as the global allocator of a binary, then [`measure`] how many
+//! allocations a closure makes, how many bytes they ask for and how much memory is in use at the
+//! peak. The counters are shared by every thread, so measure one thing at a time.
+
+use std::{
+    alloc::{GlobalAlloc, Layout, System},
+    fmt,
+    iter::Sum,
+    ops::Add,
+    sync::atomic::{AtomicUsize, Ordering},
+};
+
+static COUNT: AtomicUsize = AtomicUsize::new(0);
+static BYTES: AtomicUsize = AtomicUsize::new(0);
+static CURRENT: AtomicUsize = AtomicUsize::new(0);
+static PEAK: AtomicUsize = AtomicUsize::new(0);
+
+/// The system allocator, counting every allocation on the way through.
+pub struct CountingAllocator;
+
+impl CountingAllocator {
+    fn grow(by: usize) {
+        let current = CURRENT.fetch_add(by, Ordering::Relaxed) + by;
+        PEAK.fetch_max(current, Ordering::Relaxed);
+    }
+}
+
+// SAFETY: Every call is passed on to the system allocator as is, only counters are updated on top.
+unsafe impl GlobalAlloc for CountingAllocator {
+    unsafe fn alloc(&self, layout: Layout) -> *mut u8 {
+        let ptr = System.alloc(layout);
+        if !ptr.is_null() {
+            COUNT.fetch_add(1, Ordering::Relaxed);
+            BYTES.fetch_add(layout.size(), Ordering::Relaxed);
+            Self::grow(layout.size());
+        }
+        ptr
+    }
+
+    unsafe fn alloc_zeroed(&self, layout: Layout) -> *mut u8 {
+        let ptr = System.alloc_zeroed(layout);
+        if !ptr.is_null() {
+            COUNT.fetch_add(1, Ordering::Relaxed);
+            BYTES.fetch_add(layout.size(), Ordering::Relaxed);
+            Self::grow(layout.size());
+        }
+        ptr
+    }
+
+    unsafe fn dealloc(&self, ptr: *mut u8, layout: Layout) {
+        System.dealloc(ptr, layout);
+        CURRENT.fetch_sub(layout.size(), Ordering::Relaxed);
+    }
+
+    /// Counts as an allocation of only the bytes it grows by, as growing a `Vec` is the usual
+    /// reason to reallocate.
+    unsafe fn realloc(&self, ptr: *mut u8, layout: Layout, new_size: usize) -> *mut u8 {
+        let new_ptr = System.realloc(ptr, layout, new_size);
+        if !new_ptr.is_null() {
+            COUNT.fetch_add(1, Ordering::Relaxed);
+            if new_size > layout.size() {
+                BYTES.fetch_add(new_size - layout.size(), Ordering::Relaxed);
+                Self::grow(new_size - layout.size());
+            } else {
+                CURRENT.fetch_sub(layout.size() - new_size, Ordering::Relaxed);
+            }
+        }
+        new_ptr
+    }
+}
+
+/// What a measured closure allocated. All zero when [`CountingAllocator`] isn't installed.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct Allocations {
+    /// The number of allocations and reallocations.
+    pub count: usize,
+    /// The bytes asked for in total, including those freed again.
+    pub bytes: usize,
+    /// The most bytes in use at once on top of what was in use before.
+    pub peak: usize,
+}
+
+/// Adds up the allocations of steps run one after another, so the peak is the highest of them.
+impl Add for Allocations {
+    type Output = Self;
+
+    fn add(self, rhs: Self) -> Self {
+        Self {
+            count: self.count + rhs.count,
+            bytes: self.bytes + rhs.bytes,
+            peak: self.peak.max(rhs.peak),
+        }
+    }
+}
+
+impl Sum for Allocations {
+    fn sum<I: Iterator<Item = Self>>(iter: I) -> Self {
+        iter.fold(Self::default(), Add::add)
+    }
+}
+
+/// Run `f` and count what it allocates.
+pub fn measure<T>(f: impl FnOnce() -> T) -> (T, Allocations) {
+    let current = CURRENT.load(Ordering::Relaxed);
+    PEAK.store(current, Ordering::Relaxed);
+    let count = COUNT.load(Ordering::Relaxed);
+    let bytes = BYTES.load(Ordering::Relaxed);
+
+    let result = f();
+
+    let allocations = Allocations {
+        count: COUNT.load(Ordering::Relaxed) - count,
+        bytes: BYTES.load(Ordering::Relaxed) - bytes,
+        peak: PEAK.load(Ordering::Relaxed).saturating_sub(current),
+    };
+    (result, allocations)
+}
+
+/// Displays a number of bytes in the binary unit that suits it, e.g. `1.50 KiB`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Bytes(pub usize);
+
+impl fmt::Display for Bytes {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        const UNITS: [&str; 4] = ["KiB", "MiB", "GiB", "TiB"];
+
+        if self.0 < 1024 {
+            return write!(f, "{} B", self.0);
+        }
+        let mut size = self.0 as f64 / 1024.0;
+        let mut unit = 0;
+        while size >= 1024.0 && unit < UNITS.len() - 1 {
+            size /= 1024.0;
+            unit += 1;
+        }
+        write!(f, "{size:.2} {}", UNITS[unit])
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_bytes() {
+        assert_eq!(Bytes(0).to_string(), "0 B");
+        assert_eq!(Bytes(1023).to_string(), "1023 B");
+        assert_eq!(Bytes(1536).to_string(), "1.50 KiB");
+        assert_eq!(Bytes(3 * 1024 * 1024).to_string(), "3.00 MiB");
+    }
+
+    #[test]
+    fn test_sum() {
+        let step = |count, bytes, peak| Allocations { count, bytes, peak };
+        assert_eq!(
+            [step(1, 10, 10), step(2, 40, 30)]
+                .into_iter()
+                .sum::<Allocations>(),
+            step(3, 50, 30)
+        );
+    }
+
+    #[test]
+    fn test_measure_without_counting_allocator() {
+        let (numbers, allocations) = measure(|| vec![1, 2, 3]);
+        assert_eq!(numbers.len(), 3);
+        assert_eq!(allocations, Allocations::default());
+    }
+}
@@ -0,0 +1,255 @@
+//! The answer to a part of a puzzle, whether it's a number or text.
+
+use std::{convert::Infallible, fmt, str::FromStr};
+
+/// The answer to a part of a puzzle.
+///
+/// Numbers are kept as `i128`, so that any `u64` or `i64` answer fits and sums or products of
+/// them can be checked instead of silently wrapping. Answers compare equal to the plain values
+/// they were made from, e.g. `Answer::from(4361_u32) == 4361` and `Answer::from("CMZ") == "CMZ"`.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum Answer {
+    Number(i128),
+    Text(String),
+    /// The part has not been solved yet.
+    Unsolved,
+}
+
+impl Answer {
+    /// The answer as a number, if it is one.
+    #[must_use]
+    pub fn number(&self) -> Option<i128> {
+        match self {
+            Self::Number(number) => Some(*number),
+            _ => None,
+        }
+    }
+
+    fn checked(&self, rhs: Self, op: fn(i128, i128) -> Option<i128>) -> Option<Self> {
+        op(self.number()?, rhs.number()?).map(Self::Number)
+    }
+
+    /// Add a number to this one, `None` if either isn't a number or the sum overflows.
+    #[must_use]
+    pub fn checked_add(&self, rhs: impl Into<Self>) -> Option<Self> {
+        self.checked(rhs.into(), i128::checked_add)
+    }
+
+    /// Subtract a number from this one, `None` if either isn't a number or the result overflows.
+    #[must_use]
+    pub fn checked_sub(&self, rhs: impl Into<Self>) -> Option<Self> {
+        self.checked(rhs.into(), i128::checked_sub)
+    }
+
+    /// Multiply this number by another, `None` if either isn't a number or the product overflows.
+    #[must_use]
+    pub fn checked_mul(&self, rhs: impl Into<Self>) -> Option<Self> {
+        self.checked(rhs.into(), i128::checked_mul)
+    }
+
+    /// Sum numbers, `None` if any isn't a number or the sum overflows.
+    pub fn checked_sum<T: Into<Self>>(items: impl IntoIterator<Item = T>) -> Option<Self> {
+        items
+            .into_iter()
+            .try_fold(Self::Number(0), |sum, item| sum.checked_add(item))
+    }
+
+    /// Multiply numbers, `None` if any isn't a number or the product overflows.
+    pub fn checked_product<T: Into<Self>>(items: impl IntoIterator<Item = T>) -> Option<Self> {
+        items
+            .into_iter()
+            .try_fold(Self::Number(1), |product, item| product.checked_mul(item))
+    }
+}
+
+impl fmt::Display for Answer {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Number(number) => write!(f, "{number}"),
+            Self::Text(text) => write!(f, "{text}"),
+            Self::Unsolved => write!(f, "unsolved"),
+        }
+    }
+}
+
+/// Reads an answer back from how it's displayed: numbers as numbers and anything else as text.
+/// Text that only parses as a number when written differently, like `007` or `+5`, stays text.
+impl FromStr for Answer {
+    type Err = Infallible;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Ok(match s.parse::<i128>() {
+            Ok(number) if number.to_string() == s => Self::Number(number),
+            _ => Self::Text(s.to_string()),
+        })
+    }
+}
+
+macro_rules! impl_number {
+    ($($ty:ty),*) => {
+        $(
+            impl From<$ty> for Answer {
+                fn from(number: $ty) -> Self {
+                    Self::Number(number.into())
+                }
+            }
+
+            impl PartialEq<$ty> for Answer {
+                fn eq(&self, other: &$ty) -> bool {
+                    self.number() == Some((*other).into())
+                }
+            }
+
+            impl PartialEq<Answer> for $ty {
+                fn eq(&self, other: &Answer) -> bool {
+                    other == self
+                }
+            }
+        )*
+    };
+}
+
+impl_number!(u8, u16, u32, u64, i8, i16, i32, i64, i128);
+
+// `usize` and `isize` have no lossless `Into<i128>`, though they are at most 64 bits wide on every
+// platform Rust supports.
+impl From<usize> for Answer {
+    fn from(number: usize) -> Self {
+        Self::Number(number as i128)
+    }
+}
+
+impl PartialEq<usize> for Answer {
+    fn eq(&self, other: &usize) -> bool {
+        self.number() == Some(*other as i128)
+    }
+}
+
+impl PartialEq<Answer> for usize {
+    fn eq(&self, other: &Answer) -> bool {
+        other == self
+    }
+}
+
+impl From<isize> for Answer {
+    fn from(number: isize) -> Self {
+        Self::Number(number as i128)
+    }
+}
+
+impl PartialEq<isize> for Answer {
+    fn eq(&self, other: &isize) -> bool {
+        self.number() == Some(*other as i128)
+    }
+}
+
+impl PartialEq<Answer> for isize {
+    fn eq(&self, other: &Answer) -> bool {
+        other == self
+    }
+}
+
+impl From<String> for Answer {
+    fn from(text: String) -> Self {
+        Self::Text(text)
+    }
+}
+
+impl From<&str> for Answer {
+    fn from(text: &str) -> Self {
+        Self::Text(text.to_string())
+    }
+}
+
+impl PartialEq<str> for Answer {
+    fn eq(&self, other: &str) -> bool {
+        matches!(self, Self::Text(text) if text == other)
+    }
+}
+
+impl PartialEq<&str> for Answer {
+    fn eq(&self, other: &&str) -> bool {
+        self == *other
+    }
+}
+
+impl PartialEq<String> for Answer {
+    fn eq(&self, other: &String) -> bool {
+        self == other.as_str()
+    }
+}
+
+impl PartialEq<Answer> for &str {
+    fn eq(&self, other: &Answer) -> bool {
+        other == self
+    }
+}
+
+impl PartialEq<Answer> for String {
+    fn eq(&self, other: &Answer) -> bool {
+        other == self
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_display() {
+        assert_eq!(Answer::from(-3).to_string(), "-3");
+        assert_eq!(Answer::from(u64::MAX).to_string(), "18446744073709551615");
+        assert_eq!(Answer::from("CMZ").to_string(), "CMZ");
+        assert_eq!(Answer::Unsolved.to_string(), "unsolved");
+    }
+
+    #[test]
+    fn test_from_str() {
+        assert_eq!("-3".parse::<Answer>().unwrap(), -3);
+        assert_eq!("CMZ".parse::<Answer>().unwrap(), "CMZ");
+        assert_eq!("007".parse::<Answer>().unwrap(), "007");
+        assert_eq!("+5".parse::<Answer>().unwrap(), "+5");
+        assert_eq!("-0".parse::<Answer>().unwrap(), "-0");
+
+        for answer in [
+            Answer::from(-3),
+            Answer::from(0),
+            Answer::from("007"),
+            Answer::from("+5"),
+            Answer::from("CMZ"),
+        ] {
+            assert_eq!(answer.to_string().parse::<Answer>().unwrap(), answer);
+        }
+    }
+
+    #[test]
+    fn test_eq() {
+        assert_eq!(Answer::from(4361_u32), 4361);
+        assert_eq!(4361, Answer::from(4361_u32));
+        assert_eq!(Answer::from(1816_usize), 1816_usize);
+        assert_ne!(Answer::from(4361_u32), 4362);
+        assert_eq!(Answer::from("CMZ".to_string()), "CMZ");
+        assert_eq!("CMZ".to_string(), Answer::from("CMZ"));
+        assert_ne!(Answer::from("4361"), 4361);
+        assert_ne!(Answer::Unsolved, 0);
+    }
+
+    #[test]
+    fn test_checked() {
+        let answer = Answer::from(u32::MAX);
+        assert_eq!(
+            answer.checked_mul(u32::MAX),
+            Some(Answer::from(u64::from(u32::MAX) * u64::from(u32::MAX)))
+        );
+        assert_eq!(answer.checked_sub(u32::MAX), Some(Answer::from(0)));
+        assert_eq!(Answer::from(i128::MAX).checked_add(1), None);
+        assert_eq!(Answer::from("CMZ").checked_add(1), None);
+
+        assert_eq!(Answer::checked_sum([1, 2, 3]), Some(Answer::from(6)));
+        assert_eq!(
+            Answer::checked_product([2_u64, 3, 4]),
+            Some(Answer::from(24))
+        );
+        assert_eq!(Answer::checked_product([i128::MAX, 2]), None);
+    }
+}
@@ -0,0 +1,364 @@
+//! Confirmed answers of a year, and checking solutions against them.
+//!
+//! The answers of a year live in `answers.txt` next to its real inputs, one per line as
+//! `<day> <part> <answer>`, e.g. `5 1 CMZ`. Blank lines and lines starting with `#` are skipped.
+
+use std::{
+    collections::BTreeMap,
+    fmt, fs, io,
+    path::{Path, PathBuf},
+};
+
+use crate::{
+    answer::Answer,
+    diagnostic::ParseError,
+    input::{Input, InputError},
+    solution::{Day, Part, Registry},
+};
+
+/// Answers confirmed by submitting them, by day and part.
+#[derive(Debug, Default, Clone, PartialEq, Eq)]
+pub struct Answers {
+    answers: BTreeMap<(u8, Part), Answer>,
+}
+
+impl Answers {
+    #[must_use]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Parse the contents of an answers file.
+    /// # Errors
+    /// Returns an error pointing at the first malformed line.
+    pub fn parse(input: &Input) -> Result<Self, ParseError> {
+        let mut answers = Self::new();
+        for line in input.lines() {
+            if line.trim().is_empty() || line.starts_with('#') {
+                continue;
+            }
+
+            let mut parts = line.splitn(3, ' ');
+            let (Some(day), Some(part), Some(answer)) = (parts.next(), parts.next(), parts.next())
+            else {
+                return Err(input.error_at(line, "expected `<day> <part> <answer>`"));
+            };
+            let day = input.parse_at(day)?;
+            let part = input.parse_at(part)?;
+            if answers.insert(day, part, input.parse_at(answer)?).is_some() {
+                return Err(input.error_at(line, format!("day {day} part {part} is listed twice")));
+            }
+        }
+        Ok(answers)
+    }
+
+    /// Load the answers file at `path`. A missing file has no answers yet.
+    /// # Errors
+    /// Returns an error if the file can't be read or is malformed.
+    pub fn load(path: impl Into<PathBuf>) -> Result<Self, InputError> {
+        Input::parse_path_or_default(path, Self::parse)
+    }
+
+    /// Write the answers to `path`, replacing what was there. The file is regenerated from the
+    /// answers alone, so comments in it are not kept.
+    /// # Errors
+    /// Returns an error if the file can't be written.
+    pub fn save(&self, path: &Path) -> io::Result<()> {
+        fs::write(path, self.to_string())
+    }
+
+    #[must_use]
+    pub fn get(&self, day: u8, part: Part) -> Option<&Answer> {
+        self.answers.get(&(day, part))
+    }
+
+    /// Set the answer of a part, returning the answer it replaces.
+    pub fn insert(&mut self, day: u8, part: Part, answer: Answer) -> Option<Answer> {
+        self.answers.insert((day, part), answer)
+    }
+
+    /// Keep the answer of a check as confirmed, if there wasn't one yet. Returns whether it was
+    /// kept. Confirmed answers are never replaced, so a regression can't be recorded over.
+    pub fn record(&mut self, check: &Check) -> bool {
+        match (&check.status, &check.answer) {
+            (Status::Unknown, Ok(answer)) if *answer != Answer::Unsolved => {
+                self.insert(check.day, check.part, answer.clone());
+                true
+            }
+            _ => false,
+        }
+    }
+}
+
+impl fmt::Display for Answers {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        writeln!(
+            f,
+            "# Confirmed answers, one per line as `<day> <part> <answer>`. Regenerated by `--record`."
+        )?;
+        for ((day, part), answer) in &self.answers {
+            writeln!(f, "{day} {part} {answer}")?;
+        }
+        Ok(())
+    }
+}
+
+/// How an answer compares to the confirmed answer.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Status {
+    /// The answer is the confirmed one.
+    Pass,
+    /// No answer came out, because the input is malformed or can't be read, or the part failed.
+    Fail,
+    /// Not checked, because the input isn't present in this checkout.
+    Skipped,
+    /// There is no confirmed answer to compare with yet.
+    Unknown,
+    /// The answer differs from the confirmed one.
+    Regression { answer: Answer, expected: Answer },
+}
+
+impl fmt::Display for Status {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Pass => write!(f, "pass"),
+            Self::Fail => write!(f, "fail"),
+            Self::Skipped => write!(f, "skipped"),
+            Self::Unknown => write!(f, "unknown"),
+            Self::Regression { .. } => write!(f, "regression"),
+        }
+    }
+}
+
+/// A part of a day checked against its confirmed answer.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Check {
+    pub year: u16,
+    pub day: u8,
+    pub part: Part,
+    /// The answer, or why there is none.
+    pub answer: Result<Answer, String>,
+    pub expected: Option<Answer>,
+    pub status: Status,
+}
+
+impl Check {
+    fn new(day: &Day, part: Part, answer: Result<Answer, String>, answers: &Answers) -> Self {
+        let expected = answers.get(day.day, part).cloned();
+        let status = match (&answer, &expected) {
+            (Err(_), _) => Status::Fail,
+            (Ok(_), None) => Status::Unknown,
+            (Ok(answer), Some(expected)) if answer == expected => Status::Pass,
+            (Ok(answer), Some(expected)) => Status::Regression {
+                answer: answer.clone(),
+                expected: expected.clone(),
+            },
+        };
+        Self::with_status(day, part, answer, expected, status)
+    }
+
+    fn skipped(day: &Day, part: Part, answers: &Answers) -> Self {
+        let expected = answers.get(day.day, part).cloned();
+        let answer = Err("input not present".to_string());
+        Self::with_status(day, part, answer, expected, Status::Skipped)
+    }
+
+    fn with_status(
+        day: &Day,
+        part: Part,
+        answer: Result<Answer, String>,
+        expected: Option<Answer>,
+        status: Status,
+    ) -> Self {
+        Self {
+            year: day.year,
+            day: day.day,
+            part,
+            answer,
+            expected,
+            status,
+        }
+    }
+}
+
+impl fmt::Display for Check {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "{} day {} part {}: {}",
+            self.year, self.day, self.part, self.status
+        )?;
+        match (&self.status, &self.answer) {
+            (Status::Regression { answer, expected }, _) => {
+                write!(f, ", answered {answer} but expected {expected}")
+            }
+            (_, Err(err)) => write!(f, ", {err}"),
+            _ => Ok(()),
+        }
+    }
+}
+
+/// Solve both parts of a day on its real input and check them against the confirmed answers.
+/// Both are skipped when the input isn't present.
+#[must_use]
+pub fn verify(day: &Day, answers: &Answers) -> Vec<Check> {
+    let parsed = match day.inputs().load(day.day) {
+        Err(err) if err.is_missing() => {
+            return Part::ALL
+                .into_iter()
+                .map(|part| Check::skipped(day, part, answers))
+                .collect();
+        }
+        input => input
+            .map_err(|err| err.to_string())
+            .and_then(|input| day.parse(&input).map_err(|err| err.to_string())),
+    };
+
+    Part::ALL
+        .into_iter()
+        .map(|part| {
+            let answer = match &parsed {
+                Ok(parsed) => day.solve(parsed, part).map_err(|err| err.to_string()),
+                Err(err) => Err(err.clone()),
+            };
+            Check::new(day, part, answer, answers)
+        })
+        .collect()
+}
+
+/// Check every day of `registry` against the confirmed answers of its year, for the tests of a
+/// year's crate. Parts without a real input are skipped and say so, and parts without a confirmed
+/// answer pass.
+/// # Panics
+/// Panics at the first part that fails or regressed, or if an answers file can't be loaded.
+pub fn assert_answers(registry: &Registry) {
+    let days = registry.days().collect::<Vec<_>>();
+    for days in days.chunk_by(|a, b| a.year == b.year) {
+        let answers =
+            Answers::load(days[0].inputs().answers_path()).unwrap_or_else(|err| panic!("{err}"));
+        for check in days.iter().flat_map(|day| verify(day, &answers)) {
+            match check.status {
+                Status::Pass | Status::Unknown => {}
+                Status::Skipped => eprintln!("{check}"),
+                Status::Fail | Status::Regression { .. } => panic!("{check}"),
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        input::InputLoader,
+        solution::{tests::Sum, Registry},
+    };
+
+    #[test]
+    fn test_parse() {
+        let input = Input::from_contents("# comment\n1 1 71471\n\n5 2 MCD\n");
+        let answers = Answers::parse(&input).unwrap();
+        assert_eq!(answers.get(1, Part::One), Some(&Answer::from(71471)));
+        assert_eq!(answers.get(5, Part::Two), Some(&Answer::from("MCD")));
+        assert_eq!(answers.get(5, Part::One), None);
+
+        let reparsed = Answers::parse(&Input::from_contents(answers.to_string())).unwrap();
+        assert_eq!(reparsed, answers);
+
+        let err = Answers::parse(&Input::from_contents("1 3 71471\n")).unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "line 1, column 3: invalid `3`: expected part 1 or 2"
+        );
+        let err = Answers::parse(&Input::from_contents("1 1\n")).unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "line 1, column 1: expected `<day> <part> <answer>`"
+        );
+    }
+
+    #[test]
+    fn test_verify() {
+        let dir = std::env::temp_dir().join("aoc-core-verify-test");
+        fs::create_dir_all(&dir).unwrap();
+        fs::write(dir.join("day1.txt"), "1\n2\n").unwrap();
+        fs::write(dir.join("day2.txt"), "1\nx\n").unwrap();
+
+        let inputs = InputLoader::new(2000, env!("CARGO_MANIFEST_DIR")).dir(&dir);
+        let mut registry = Registry::new();
+        registry.register::<Sum>(&inputs, 1);
+        registry.register::<Sum>(&inputs, 2);
+        registry.register::<Sum>(&inputs, 3);
+        let day = |day| registry.get(2000, day).unwrap();
+
+        let mut answers = Answers::new();
+        let statuses =
+            |checks: Vec<Check>| checks.into_iter().map(|c| c.status).collect::<Vec<_>>();
+        assert_eq!(
+            statuses(verify(day(1), &answers)),
+            [Status::Unknown, Status::Unknown]
+        );
+
+        let checks = verify(day(1), &answers);
+        assert!(answers.record(&checks[0]));
+        assert!(!answers.record(&checks[1]));
+        assert_eq!(
+            statuses(verify(day(1), &answers)),
+            [Status::Pass, Status::Unknown]
+        );
+
+        answers.insert(1, Part::One, Answer::from(4));
+        let checks = verify(day(1), &answers);
+        assert_eq!(
+            checks[0].status,
+            Status::Regression {
+                answer: Answer::from(3),
+                expected: Answer::from(4)
+            }
+        );
+        assert_eq!(
+            checks[0].to_string(),
+            "2000 day 1 part 1: regression, answered 3 but expected 4"
+        );
+        assert!(!answers.record(&checks[0]));
+
+        assert_eq!(
+            statuses(verify(day(2), &answers)),
+            [Status::Fail, Status::Fail]
+        );
+        let checks = verify(day(3), &answers);
+        assert_eq!(statuses(checks.clone()), [Status::Skipped, Status::Skipped]);
+        assert_eq!(
+            checks[0].to_string(),
+            "2000 day 3 part 1: skipped, input not present"
+        );
+    }
+
+    #[test]
+    fn test_assert_answers() {
+        let dir = std::env::temp_dir().join("aoc-core-assert-answers-test");
+        fs::create_dir_all(&dir).unwrap();
+        fs::write(dir.join("day1.txt"), "1\n2\n").unwrap();
+        fs::write(dir.join("answers.txt"), "1 1 3\n").unwrap();
+
+        let inputs = InputLoader::new(2000, env!("CARGO_MANIFEST_DIR")).dir(&dir);
+        let mut registry = Registry::new();
+        registry.register::<Sum>(&inputs, 1);
+        registry.register::<Sum>(&inputs, 2);
+        assert_answers(&registry);
+    }
+
+    #[test]
+    #[should_panic(expected = "2000 day 1 part 1: regression, answered 3 but expected 4")]
+    fn test_assert_answers_regression() {
+        let dir = std::env::temp_dir().join("aoc-core-assert-answers-regression-test");
+        fs::create_dir_all(&dir).unwrap();
+        fs::write(dir.join("day1.txt"), "1\n2\n").unwrap();
+        fs::write(dir.join("answers.txt"), "1 1 4\n").unwrap();
+
+        let inputs = InputLoader::new(2000, env!("CARGO_MANIFEST_DIR")).dir(&dir);
+        let mut registry = Registry::new();
+        registry.register::<Sum>(&inputs, 1);
+        assert_answers(&registry);
+    }
+}
@@ -0,0 +1,308 @@
+//! Benchmark baselines, and telling a slowdown from noise.
+//!
+//! A baseline keeps how long each step of each day took, one per line as
+//! `<year> <day> <step> <runs> <mean> <std dev>`, e.g. `2022 8 1 30 713402 10380`. The step is
+//! `parse`, `1` or `2`, and durations are in nanoseconds. Blank lines and lines starting with `#`
+//! are skipped.
+
+use std::{
+    collections::BTreeMap,
+    fmt, fs, io,
+    path::{Path, PathBuf},
+    time::Duration,
+};
+
+use crate::{
+    diagnostic::ParseError,
+    input::{Input, InputError},
+    solution::Part,
+};
+
+/// A step of solving a day that is timed on its own.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum Step {
+    Parse,
+    Part(Part),
+}
+
+impl Step {
+    pub const ALL: [Self; 3] = [Self::Parse, Self::Part(Part::One), Self::Part(Part::Two)];
+
+    fn parse_token(input: &Input, token: &str) -> Result<Self, ParseError> {
+        match token {
+            "parse" => Ok(Self::Parse),
+            _ => input
+                .parse_at::<Part>(token)
+                .map(Self::Part)
+                .map_err(|_| input.error_at(token, "expected a step of `parse`, `1` or `2`")),
+        }
+    }
+
+    fn token(self) -> String {
+        match self {
+            Self::Parse => "parse".to_string(),
+            Self::Part(part) => part.to_string(),
+        }
+    }
+}
+
+impl fmt::Display for Step {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Parse => write!(f, "parse"),
+            Self::Part(part) => write!(f, "part {part}"),
+        }
+    }
+}
+
+/// The mean and standard deviation of how long repeated runs of a step took.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Sample {
+    pub runs: usize,
+    pub mean: Duration,
+    pub std_dev: Duration,
+}
+
+/// How many standard errors apart two means have to be to call the difference real. Around 99.7%
+/// confidence for a normal distribution, which keeps noisy machines from crying wolf.
+const SIGNIFICANCE: f64 = 3.0;
+
+/// How much the mean has to change as well, so tiny but consistent differences aren't reported.
+const MIN_CHANGE: f64 = 0.05;
+
+impl Sample {
+    /// Summarize the durations of runs.
+    /// # Panics
+    /// Panics if there are no durations.
+    #[must_use]
+    pub fn from_durations(durations: &[Duration]) -> Self {
+        assert!(!durations.is_empty(), "expect at least one duration");
+        let runs = durations.len();
+        let secs = durations.iter().map(Duration::as_secs_f64);
+        let mean = secs.clone().sum::<f64>() / runs as f64;
+        let variance = if runs > 1 {
+            secs.map(|secs| (secs - mean).powi(2)).sum::<f64>() / (runs - 1) as f64
+        } else {
+            0.0
+        };
+
+        Self {
+            runs,
+            mean: Duration::from_secs_f64(mean),
+            std_dev: Duration::from_secs_f64(variance.sqrt()),
+        }
+    }
+
+    /// How the mean compares to the mean of a baseline, using Welch's t-test.
+    #[must_use]
+    pub fn compare(&self, baseline: &Self) -> Change {
+        let (mean, base) = (self.mean.as_secs_f64(), baseline.mean.as_secs_f64());
+        let standard_error =
+            |sample: &Self| sample.std_dev.as_secs_f64() / (sample.runs as f64).sqrt();
+        // The standard error of the difference between the means.
+        let error = standard_error(self).hypot(standard_error(baseline));
+        let difference = mean - base;
+
+        let significant = if error > 0.0 {
+            (difference / error).abs() > SIGNIFICANCE
+        } else {
+            difference != 0.0
+        };
+        if !significant || difference.abs() <= base * MIN_CHANGE {
+            Change::Unchanged
+        } else if difference > 0.0 {
+            Change::Slower(difference / base)
+        } else {
+            Change::Faster(-difference / base)
+        }
+    }
+}
+
+/// How a sample compares to its baseline. Slower and faster carry the relative change of the mean.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum Change {
+    Slower(f64),
+    Faster(f64),
+    /// Any difference is within the noise.
+    Unchanged,
+}
+
+impl fmt::Display for Change {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Slower(change) => write!(f, "slower by {:.1}%", change * 100.0),
+            Self::Faster(change) => write!(f, "faster by {:.1}%", change * 100.0),
+            Self::Unchanged => write!(f, "unchanged"),
+        }
+    }
+}
+
+/// Samples of a previous benchmark to compare later ones against, by year, day and step.
+#[derive(Debug, Default, Clone, PartialEq, Eq)]
+pub struct Baseline {
+    samples: BTreeMap<(u16, u8, Step), Sample>,
+}
+
+impl Baseline {
+    #[must_use]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Parse the contents of a baseline file.
+    /// # Errors
+    /// Returns an error pointing at the first malformed line.
+    pub fn parse(input: &Input) -> Result<Self, ParseError> {
+        let mut baseline = Self::new();
+        for line in input.lines() {
+            if line.trim().is_empty() || line.starts_with('#') {
+                continue;
+            }
+
+            let fields = line.split_whitespace().collect::<Vec<_>>();
+            let [year, day, step, runs, mean, std_dev] = fields[..] else {
+                return Err(input.error_at(
+                    line,
+                    "expected `<year> <day> <step> <runs> <mean> <std dev>`",
+                ));
+            };
+            let sample = Sample {
+                runs: input.parse_at(runs)?,
+                mean: Duration::from_nanos(input.parse_at(mean)?),
+                std_dev: Duration::from_nanos(input.parse_at(std_dev)?),
+            };
+            let (year, day, step) = (
+                input.parse_at(year)?,
+                input.parse_at(day)?,
+                Step::parse_token(input, step)?,
+            );
+            if baseline.insert(year, day, step, sample).is_some() {
+                return Err(
+                    input.error_at(line, format!("{year} day {day} {step} is listed twice"))
+                );
+            }
+        }
+        Ok(baseline)
+    }
+
+    /// Load the baseline file at `path`. A missing file has no samples yet.
+    /// # Errors
+    /// Returns an error if the file can't be read or is malformed.
+    pub fn load(path: impl Into<PathBuf>) -> Result<Self, InputError> {
+        Input::parse_path_or_default(path, Self::parse)
+    }
+
+    /// Write the baseline to `path`, creating its directory if needed.
+    /// # Errors
+    /// Returns an error if the file can't be written.
+    pub fn save(&self, path: &Path) -> io::Result<()> {
+        if let Some(dir) = path.parent() {
+            fs::create_dir_all(dir)?;
+        }
+        fs::write(path, self.to_string())
+    }
+
+    #[must_use]
+    pub fn get(&self, year: u16, day: u8, step: Step) -> Option<&Sample> {
+        self.samples.get(&(year, day, step))
+    }
+
+    /// Set the sample of a step, returning the sample it replaces.
+    pub fn insert(&mut self, year: u16, day: u8, step: Step, sample: Sample) -> Option<Sample> {
+        self.samples.insert((year, day, step), sample)
+    }
+}
+
+impl fmt::Display for Baseline {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        writeln!(
+            f,
+            "# Benchmark baseline, one per line as `<year> <day> <step> <runs> <mean> <std dev>` in ns."
+        )?;
+        for ((year, day, step), sample) in &self.samples {
+            writeln!(
+                f,
+                "{year} {day} {} {} {} {}",
+                step.token(),
+                sample.runs,
+                sample.mean.as_nanos(),
+                sample.std_dev.as_nanos()
+            )?;
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn sample(runs: usize, mean: u64, std_dev: u64) -> Sample {
+        Sample {
+            runs,
+            mean: Duration::from_micros(mean),
+            std_dev: Duration::from_micros(std_dev),
+        }
+    }
+
+    #[test]
+    fn test_from_durations() {
+        let sample = Sample::from_durations(&[2, 4, 4, 4, 5, 5, 7, 9].map(Duration::from_micros));
+        assert_eq!(sample.runs, 8);
+        assert_eq!(sample.mean, Duration::from_micros(5));
+        // The sample standard deviation, dividing by n - 1.
+        assert_eq!(sample.std_dev.as_nanos(), 2138);
+    }
+
+    #[test]
+    fn test_compare() {
+        let baseline = sample(30, 100, 5);
+        assert_eq!(sample(30, 102, 5).compare(&baseline), Change::Unchanged);
+        assert_eq!(
+            sample(30, 120, 5).compare(&baseline).to_string(),
+            "slower by 20.0%"
+        );
+        assert_eq!(
+            sample(30, 50, 5).compare(&baseline).to_string(),
+            "faster by 50.0%"
+        );
+        // A big change in the mean that is within the noise.
+        assert_eq!(sample(3, 120, 40).compare(&baseline), Change::Unchanged);
+        // A significant change too small to care about.
+        assert_eq!(
+            sample(30, 103, 0).compare(&sample(30, 100, 0)),
+            Change::Unchanged
+        );
+    }
+
+    #[test]
+    fn test_parse() {
+        let input =
+            Input::from_contents("# comment\n2022 8 1 30 713402 10380\n\n2023 3 parse 30 9 0\n");
+        let baseline = Baseline::parse(&input).unwrap();
+        assert_eq!(
+            baseline.get(2022, 8, Step::Part(Part::One)),
+            Some(&Sample {
+                runs: 30,
+                mean: Duration::from_nanos(713_402),
+                std_dev: Duration::from_nanos(10_380),
+            })
+        );
+        assert!(baseline.get(2023, 3, Step::Parse).is_some());
+        assert_eq!(baseline.get(2023, 3, Step::Part(Part::Two)), None);
+
+        let reparsed = Baseline::parse(&Input::from_contents(baseline.to_string())).unwrap();
+        assert_eq!(reparsed, baseline);
+
+        let err = Baseline::parse(&Input::from_contents("2022 8 3 30 1 1\n")).unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "line 1, column 8: expected a step of `parse`, `1` or `2`"
+        );
+        let err = Baseline::parse(&Input::from_contents("2022 8 1 30\n")).unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "line 1, column 1: expected `<year> <day> <step> <runs> <mean> <std dev>`"
+        );
+    }
+}
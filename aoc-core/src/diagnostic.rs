@@ -0,0 +1,169 @@
+//! Errors in the contents of an input, and rendering them as a diagnostic pointing at the bad text.
+
+use std::{error, fmt};
+
+use crate::input::{Input, InputError, LineError};
+
+/// A stretch of text on one line of an input. Lines and columns are 1-based and counted in chars.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Span {
+    pub line: usize,
+    pub column: usize,
+    /// The number of chars, 0 for a point between two chars such as the end of a line.
+    pub len: usize,
+}
+
+/// An input that could not be parsed, with where it went wrong when that's known.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ParseError {
+    pub message: String,
+    pub span: Option<Span>,
+}
+
+impl ParseError {
+    /// An error about the input as a whole.
+    #[must_use]
+    pub fn new(message: impl Into<String>) -> Self {
+        Self {
+            message: message.into(),
+            span: None,
+        }
+    }
+
+    /// An error about the text at `span`.
+    #[must_use]
+    pub fn at(span: Span, message: impl Into<String>) -> Self {
+        Self {
+            message: message.into(),
+            span: Some(span),
+        }
+    }
+
+    /// Render the error like a compiler would, quoting the line of `input` it is about with a
+    /// caret under the bad text:
+    ///
+    /// ```txt
+    /// unknown command `rm`
+    ///  --> line 3, column 3
+    ///   |
+    /// 3 | $ rm -rf
+    ///   |   ^^
+    /// ```
+    #[must_use]
+    pub fn diagnostic(&self, input: &Input) -> String {
+        let Some(span) = self.span else {
+            return self.message.clone();
+        };
+        let Some(text) = input.lines().nth(span.line - 1) else {
+            return self.to_string();
+        };
+
+        let gutter = span.line.to_string();
+        let padding = " ".repeat(gutter.len());
+        let indent = " ".repeat(span.column - 1);
+        // Underline at least one char, and nothing past the end of the line but the end itself.
+        let available = (text.chars().count() + 1).saturating_sub(span.column);
+        let carets = "^".repeat(span.len.clamp(1, available.max(1)));
+
+        format!(
+            "{message}\n{padding}--> line {line}, column {column}\n{padding} |\n{gutter} | {text}\n{padding} | {indent}{carets}",
+            message = self.message,
+            line = span.line,
+            column = span.column,
+        )
+    }
+}
+
+impl fmt::Display for ParseError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self.span {
+            Some(Span { line, column, .. }) => {
+                write!(f, "line {line}, column {column}: {}", self.message)
+            }
+            None => write!(f, "{}", self.message),
+        }
+    }
+}
+
+impl error::Error for ParseError {}
+
+impl From<InputError> for ParseError {
+    fn from(err: InputError) -> Self {
+        match err {
+            InputError::RaggedRow { row, width, .. } => Self::at(
+                Span {
+                    line: row,
+                    column: 1,
+                    len: width,
+                },
+                err.to_string(),
+            ),
+            _ => Self::new(err.to_string()),
+        }
+    }
+}
+
+impl<E: fmt::Display> From<LineError<E>> for ParseError {
+    fn from(err: LineError<E>) -> Self {
+        Self::at(
+            Span {
+                line: err.line,
+                column: 1,
+                len: err.text.chars().count(),
+            },
+            err.source.to_string(),
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_diagnostic() {
+        let input = Input::from_contents("$ cd /\n$ rm -rf\n");
+        let line = input.lines().nth(1).unwrap();
+        let err = input.error_at(&line[2..4], "unknown command `rm`");
+
+        assert_eq!(err.to_string(), "line 2, column 3: unknown command `rm`");
+        assert_eq!(
+            err.diagnostic(&input),
+            "unknown command `rm`\n --> line 2, column 3\n  |\n2 | $ rm -rf\n  |   ^^"
+        );
+    }
+
+    #[test]
+    fn test_diagnostic_end_of_line() {
+        let input = Input::from_contents("move 1 from 2");
+        let line = input.lines().next().unwrap();
+        let err = input.error_at(&line[line.len()..], "expected ` to `");
+
+        assert_eq!(
+            err.diagnostic(&input),
+            "expected ` to `\n --> line 1, column 14\n  |\n1 | move 1 from 2\n  |              ^"
+        );
+    }
+
+    #[test]
+    fn test_diagnostic_without_span() {
+        let input = Input::from_contents("1\n2\n");
+        let err = ParseError::new("expected 2 sections separated by blank lines, found 1");
+        assert_eq!(err.diagnostic(&input), err.message);
+    }
+
+    #[test]
+    fn test_from_line_error() {
+        let input = Input::from_contents("1\nx\n");
+        let err = ParseError::from(input.parse_lines::<u32>().unwrap_err());
+        assert_eq!(
+            err.span,
+            Some(Span {
+                line: 2,
+                column: 1,
+                len: 1
+            })
+        );
+        assert_eq!(err.message, "invalid digit found in string");
+    }
+}
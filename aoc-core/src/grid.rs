@@ -0,0 +1,302 @@
+//! A rectangular grid of cells, with all the edge handling in one place.
+
+use std::{fmt, ops::Index};
+
+use crate::{
+    input::InputError,
+    point::{Direction, Point, Vector},
+};
+
+/// A rectangular grid, stored row by row. The top left cell is at `(0, 0)`.
+///
+/// Everything that looks at a point outside the grid gets `None` or stops there, so callers never
+/// check bounds themselves.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct Grid<T> {
+    width: usize,
+    height: usize,
+    cells: Vec<T>,
+}
+
+impl<T> Grid<T> {
+    /// A grid of rows that must all be as wide as the first.
+    /// # Errors
+    /// Returns an error naming the first row of another width.
+    pub fn from_rows(rows: impl IntoIterator<Item = Vec<T>>) -> Result<Self, InputError> {
+        let mut grid = Self {
+            width: 0,
+            height: 0,
+            cells: Vec::new(),
+        };
+        for row in rows {
+            if grid.height == 0 {
+                grid.width = row.len();
+            } else if row.len() != grid.width {
+                return Err(InputError::RaggedRow {
+                    row: grid.height + 1,
+                    width: row.len(),
+                    expected: grid.width,
+                });
+            }
+            grid.cells.extend(row);
+            grid.height += 1;
+        }
+        Ok(grid)
+    }
+
+    /// A grid with every cell set to `value`.
+    #[must_use]
+    pub fn filled(width: usize, height: usize, value: T) -> Self
+    where
+        T: Clone,
+    {
+        Self {
+            width,
+            height,
+            cells: vec![value; width * height],
+        }
+    }
+
+    #[must_use]
+    pub fn width(&self) -> usize {
+        self.width
+    }
+
+    #[must_use]
+    pub fn height(&self) -> usize {
+        self.height
+    }
+
+    fn index_of(&self, point: Point) -> Option<usize> {
+        let (x, y) = <(usize, usize)>::try_from(point).ok()?;
+        (x < self.width && y < self.height).then_some(y * self.width + x)
+    }
+
+    fn point_of(&self, index: usize) -> Point {
+        Point::try_from((index % self.width, index / self.width))
+            .expect("expect grid indices to fit in a point")
+    }
+
+    #[must_use]
+    pub fn contains(&self, point: Point) -> bool {
+        self.index_of(point).is_some()
+    }
+
+    /// The cell at `point`, `None` if it's outside the grid.
+    #[must_use]
+    pub fn get(&self, point: Point) -> Option<&T> {
+        self.index_of(point).map(|index| &self.cells[index])
+    }
+
+    /// The cell at `point`, `None` if it's outside the grid.
+    #[must_use]
+    pub fn get_mut(&mut self, point: Point) -> Option<&mut T> {
+        self.index_of(point).map(|index| &mut self.cells[index])
+    }
+
+    /// Every cell with its point, row by row.
+    pub fn iter(&self) -> impl Iterator<Item = (Point, &T)> {
+        self.cells
+            .iter()
+            .enumerate()
+            .map(|(index, cell)| (self.point_of(index), cell))
+    }
+
+    /// The rows from top to bottom.
+    pub fn rows(&self) -> impl DoubleEndedIterator<Item = &[T]> {
+        // `chunks` panics on a size of 0, which an empty grid would have.
+        self.cells.chunks(self.width.max(1))
+    }
+
+    /// The row at `y`, left to right.
+    /// # Panics
+    /// Panics if `y` is outside the grid.
+    #[must_use]
+    pub fn row(&self, y: usize) -> &[T] {
+        assert!(
+            y < self.height,
+            "row {y} outside a grid {} high",
+            self.height
+        );
+        &self.cells[y * self.width..(y + 1) * self.width]
+    }
+
+    /// The column at `x`, top to bottom.
+    /// # Panics
+    /// Panics if `x` is outside the grid.
+    pub fn column(&self, x: usize) -> impl DoubleEndedIterator<Item = &T> {
+        assert!(
+            x < self.width,
+            "column {x} outside a grid {} wide",
+            self.width
+        );
+        self.cells[x..].iter().step_by(self.width)
+    }
+
+    fn around<'a>(
+        &'a self,
+        point: Point,
+        directions: &'a [Direction],
+    ) -> impl Iterator<Item = (Point, &'a T)> + 'a {
+        directions.iter().filter_map(move |&direction| {
+            let neighbor = point + direction;
+            Some((neighbor, self.get(neighbor)?))
+        })
+    }
+
+    /// The cells up, right, down and left of `point` that are inside the grid, in that order.
+    pub fn neighbors4(&self, point: Point) -> impl Iterator<Item = (Point, &T)> {
+        self.around(point, &Direction::ORTHOGONAL)
+    }
+
+    /// The cells around `point`, diagonals included, that are inside the grid, clockwise from up.
+    pub fn neighbors8(&self, point: Point) -> impl Iterator<Item = (Point, &T)> {
+        self.around(point, &Direction::ALL)
+    }
+
+    /// The cells from `from` in steps of `step`, such as a [`Direction`], until the edge of the
+    /// grid, not including `from` itself. A ray from a cell on the edge towards that edge is empty,
+    /// and so is a ray with a zero step, which would never leave `from`.
+    pub fn ray(&self, from: Point, step: impl Into<Vector>) -> impl Iterator<Item = (Point, &T)> {
+        let step = step.into();
+        let first = (step != Vector::ZERO).then_some(from + step);
+        std::iter::successors(first, move |&point| Some(point + step))
+            .map_while(|point| Some((point, self.get(point)?)))
+    }
+
+    /// A grid of the same size with `f` applied to every cell.
+    #[must_use]
+    pub fn map<U>(&self, mut f: impl FnMut(&T) -> U) -> Grid<U> {
+        Grid {
+            width: self.width,
+            height: self.height,
+            cells: self.cells.iter().map(&mut f).collect(),
+        }
+    }
+
+    /// Like [`Grid::map`], for conversions that can fail.
+    /// # Errors
+    /// Returns the first error of `f`, going row by row.
+    pub fn try_map<U, E>(
+        &self,
+        mut f: impl FnMut(Point, &T) -> Result<U, E>,
+    ) -> Result<Grid<U>, E> {
+        Ok(Grid {
+            width: self.width,
+            height: self.height,
+            cells: self
+                .iter()
+                .map(|(point, cell)| f(point, cell))
+                .collect::<Result<_, _>>()?,
+        })
+    }
+}
+
+/// # Panics
+/// Panics if `point` is outside the grid.
+impl<T> Index<Point> for Grid<T> {
+    type Output = T;
+
+    fn index(&self, point: Point) -> &T {
+        self.get(point)
+            .unwrap_or_else(|| panic!("{point} outside a {}x{} grid", self.width, self.height))
+    }
+}
+
+/// Renders the cells of each row next to each other and the rows on lines of their own, like the
+/// input the grid came from.
+impl<T: fmt::Display> fmt::Display for Grid<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for (y, row) in self.rows().enumerate() {
+            if y > 0 {
+                writeln!(f)?;
+            }
+            for cell in row {
+                write!(f, "{cell}")?;
+            }
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn grid() -> Grid<u8> {
+        Grid::from_rows([vec![1, 2, 3], vec![4, 5, 6]]).unwrap()
+    }
+
+    #[test]
+    fn test_get() {
+        let grid = grid();
+        assert_eq!((grid.width(), grid.height()), (3, 2));
+        assert_eq!(grid.get(Point::new(2, 1)), Some(&6));
+        assert_eq!(grid[Point::new(1, 0)], 2);
+        assert_eq!(grid.get(Point::new(3, 0)), None);
+        assert_eq!(grid.get(Point::new(0, -1)), None);
+        assert!(!grid.contains(Point::new(-1, 1)));
+    }
+
+    #[test]
+    fn test_from_rows() {
+        let err = Grid::from_rows([vec![1, 2], vec![3]]).unwrap_err();
+        assert_eq!(err.to_string(), "grid row 2 is 1 wide, expected 2");
+
+        let empty = Grid::<u8>::from_rows([]).unwrap();
+        assert_eq!(empty.rows().count(), 0);
+        assert_eq!(empty.to_string(), "");
+    }
+
+    #[test]
+    fn test_neighbors() {
+        let grid = grid();
+        let values =
+            |cells: Vec<(Point, &u8)>| cells.into_iter().map(|(_, &v)| v).collect::<Vec<_>>();
+        assert_eq!(values(grid.neighbors4(Point::new(0, 0)).collect()), [2, 4]);
+        assert_eq!(
+            values(grid.neighbors4(Point::new(1, 1)).collect()),
+            [2, 6, 4]
+        );
+        assert_eq!(
+            values(grid.neighbors8(Point::new(1, 0)).collect()),
+            [3, 6, 5, 4, 1]
+        );
+    }
+
+    #[test]
+    fn test_rows_columns_and_rays() {
+        let grid = grid();
+        assert_eq!(grid.row(1), [4, 5, 6]);
+        assert_eq!(grid.column(2).copied().collect::<Vec<_>>(), [3, 6]);
+        assert_eq!(grid.rows().next_back(), Some([4, 5, 6].as_slice()));
+
+        let ray = |from, step: Direction| {
+            grid.ray(from, step)
+                .map(|(_, &value)| value)
+                .collect::<Vec<_>>()
+        };
+        assert_eq!(ray(Point::new(0, 0), Direction::Right), [2, 3]);
+        assert_eq!(ray(Point::new(0, 0), Direction::DownRight), [5]);
+        assert_eq!(ray(Point::new(2, 1), Direction::Left), [5, 4]);
+        assert!(ray(Point::new(2, 1), Direction::Down).is_empty());
+        assert_eq!(
+            grid.ray(Point::new(0, 0), Vector::new(2, 0)).count(),
+            1,
+            "a ray can skip cells"
+        );
+        assert_eq!(grid.ray(Point::new(1, 1), Vector::ZERO).count(), 0);
+    }
+
+    #[test]
+    fn test_map_and_display() {
+        let grid = grid();
+        assert_eq!(grid.to_string(), "123\n456");
+        assert_eq!(grid.map(|&v| v * 2).to_string(), "246\n81012");
+        assert_eq!(
+            grid.try_map(|point, &v| if v < 6 { Ok(v) } else { Err(point) }),
+            Err(Point::new(2, 1))
+        );
+        assert_eq!(Grid::filled(2, 1, '.').to_string(), "..");
+    }
+}
@@ -0,0 +1,736 @@
+//! Responsible for helping us read puzzle inputs.
+
+use std::{
+    env, error, fmt, fs,
+    io::{self, Read},
+    path::{Path, PathBuf},
+    str::{FromStr, Lines},
+};
+
+use crate::{
+    diagnostic::{ParseError, Span},
+    grid::Grid,
+    parser::Parser,
+};
+
+/// Environment variable pointing at a directory of inputs kept outside the repo. Inputs for a year
+/// are read from a subdirectory named after it, e.g. `$AOC_INPUT_DIR/2023/day1.txt`.
+pub const INPUT_DIR_VAR: &str = "AOC_INPUT_DIR";
+
+/// Everything that can go wrong while loading a puzzle input.
+#[derive(Debug)]
+pub enum InputError {
+    /// The input file for a day could not be read.
+    Read {
+        day: u8,
+        path: PathBuf,
+        source: io::Error,
+    },
+    /// An input file given by path could not be read.
+    ReadPath { path: PathBuf, source: io::Error },
+    /// An input could not be read from stdin or another reader.
+    ReadStream { source: io::Error },
+    /// A file given by path was read but its contents are malformed.
+    Parse { path: PathBuf, source: ParseError },
+    /// The input did not have the expected number of blank-line separated sections.
+    SectionCount { expected: usize, found: usize },
+    /// A row of a grid was not as wide as the first row.
+    RaggedRow {
+        /// The 1-based row number.
+        row: usize,
+        width: usize,
+        expected: usize,
+    },
+}
+
+impl fmt::Display for InputError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Read { day, path, source } if source.kind() == io::ErrorKind::NotFound => {
+                write!(f, "day {day}: input missing at {}", path.display())
+            }
+            Self::Read { day, path, source } => {
+                write!(f, "day {day}: failed to read {}: {source}", path.display())
+            }
+            Self::ReadPath { path, source } => {
+                write!(f, "failed to read {}: {source}", path.display())
+            }
+            Self::ReadStream { source } => write!(f, "failed to read input: {source}"),
+            Self::Parse { path, source } => write!(f, "{}: {source}", path.display()),
+            Self::SectionCount { expected, found } => {
+                write!(
+                    f,
+                    "expected {expected} sections separated by blank lines, found {found}"
+                )
+            }
+            Self::RaggedRow {
+                row,
+                width,
+                expected,
+            } => {
+                write!(f, "grid row {row} is {width} wide, expected {expected}")
+            }
+        }
+    }
+}
+
+impl InputError {
+    /// Whether the input file of a day doesn't exist, as personal inputs aren't always shared.
+    #[must_use]
+    pub fn is_missing(&self) -> bool {
+        matches!(self, Self::Read { source, .. } if source.kind() == io::ErrorKind::NotFound)
+    }
+}
+
+impl error::Error for InputError {
+    fn source(&self) -> Option<&(dyn error::Error + 'static)> {
+        match self {
+            Self::Read { source, .. }
+            | Self::ReadPath { source, .. }
+            | Self::ReadStream { source } => Some(source),
+            Self::Parse { source, .. } => Some(source),
+            Self::SectionCount { .. } | Self::RaggedRow { .. } => None,
+        }
+    }
+}
+
+/// Knows where the puzzle inputs of a year live and loads them.
+///
+/// Real inputs are read from `$AOC_INPUT_DIR/{year}` when `AOC_INPUT_DIR` is set, and from the
+/// `input` directory of the year's crate otherwise, no matter where the process was started from.
+/// Examples are always read from the `input` directory of the year's crate.
+///
+/// Examples are named `day{day}-example{n}.txt`, numbered from 1.
+#[derive(Clone, Debug)]
+pub struct InputLoader {
+    year: u16,
+    crate_dir: &'static str,
+    dir: Option<PathBuf>,
+    example_dir: Option<PathBuf>,
+}
+
+impl InputLoader {
+    /// A loader for the inputs of `year`. Year crates pass their own
+    /// `env!("CARGO_MANIFEST_DIR")` as `crate_dir`.
+    #[must_use]
+    pub const fn new(year: u16, crate_dir: &'static str) -> Self {
+        InputLoader {
+            year,
+            crate_dir,
+            dir: None,
+            example_dir: None,
+        }
+    }
+
+    #[must_use]
+    pub fn year(&self) -> u16 {
+        self.year
+    }
+
+    /// The directory of the year's crate.
+    #[must_use]
+    pub fn crate_dir(&self) -> &Path {
+        Path::new(self.crate_dir)
+    }
+
+    /// Read real inputs from `dir` instead.
+    #[must_use]
+    pub fn dir(mut self, dir: impl Into<PathBuf>) -> Self {
+        self.dir = Some(dir.into());
+        self
+    }
+
+    /// Read examples from `dir` instead.
+    #[must_use]
+    pub fn example_dir(mut self, dir: impl Into<PathBuf>) -> Self {
+        self.example_dir = Some(dir.into());
+        self
+    }
+
+    fn input_dir(&self) -> PathBuf {
+        if let Some(dir) = &self.dir {
+            return dir.clone();
+        }
+        match env::var_os(INPUT_DIR_VAR) {
+            Some(dir) => PathBuf::from(dir).join(self.year.to_string()),
+            None => Path::new(self.crate_dir).join("input"),
+        }
+    }
+
+    fn examples_dir(&self) -> PathBuf {
+        match &self.example_dir {
+            Some(dir) => dir.clone(),
+            None => Path::new(self.crate_dir).join("input"),
+        }
+    }
+
+    /// Path of the real input for a day.
+    #[must_use]
+    pub fn path(&self, day: u8) -> PathBuf {
+        self.input_dir().join(format!("day{day}.txt"))
+    }
+
+    /// Path of the confirmed answers of the year, kept next to the real inputs.
+    #[must_use]
+    pub fn answers_path(&self) -> PathBuf {
+        self.input_dir().join("answers.txt")
+    }
+
+    /// Path of an example input for a day.
+    #[must_use]
+    pub fn example_path(&self, day: u8, example: u8) -> PathBuf {
+        self.examples_dir()
+            .join(format!("day{day}-example{example}.txt"))
+    }
+
+    /// The numbers of all examples available for a day, in order.
+    /// # Errors
+    /// Returns an error if the example directory can't be read.
+    pub fn examples(&self, day: u8) -> Result<Vec<u8>, InputError> {
+        let examples_dir = self.examples_dir();
+        let read_error = |source| InputError::Read {
+            day,
+            path: examples_dir.clone(),
+            source,
+        };
+
+        let prefix = format!("day{day}-example");
+        let mut examples = vec![];
+        for entry in fs::read_dir(&examples_dir).map_err(read_error)? {
+            let file_name = entry.map_err(read_error)?.file_name();
+            let example = file_name
+                .to_str()
+                .and_then(|name| name.strip_prefix(&prefix))
+                .and_then(|name| name.strip_suffix(".txt"))
+                .and_then(|number| number.parse::<u8>().ok());
+            if let Some(example) = example {
+                examples.push(example);
+            }
+        }
+        examples.sort_unstable();
+
+        Ok(examples)
+    }
+
+    /// Load the real input for a day.
+    /// # Errors
+    /// Returns an error naming the day and path if the file can't be read.
+    pub fn load(&self, day: u8) -> Result<Input, InputError> {
+        Self::read(day, self.path(day))
+    }
+
+    /// Load an example input for a day.
+    /// # Errors
+    /// Returns an error naming the day and path if the file can't be read.
+    pub fn load_example(&self, day: u8, example: u8) -> Result<Input, InputError> {
+        Self::read(day, self.example_path(day, example))
+    }
+
+    /// Like [`InputLoader::load`].
+    /// # Panics
+    /// Panics if the file can't be read.
+    #[must_use]
+    pub fn input(&self, day: u8) -> Input {
+        self.load(day).unwrap_or_else(|err| panic!("{err}"))
+    }
+
+    /// Like [`InputLoader::input`], but `None` when the file doesn't exist, so tests on real
+    /// inputs can skip in a checkout without them. Prints `skipped: input not present at <path>` to
+    /// say so.
+    /// # Panics
+    /// Panics if the file exists but can't be read.
+    #[must_use]
+    pub fn input_if_present(&self, day: u8) -> Option<Input> {
+        match self.load(day) {
+            Ok(input) => Some(input),
+            Err(err) if err.is_missing() => {
+                eprintln!("skipped: input not present at {}", self.path(day).display());
+                None
+            }
+            Err(err) => panic!("{err}"),
+        }
+    }
+
+    /// Like [`InputLoader::load_example`].
+    /// # Panics
+    /// Panics if the file can't be read.
+    #[must_use]
+    pub fn example(&self, day: u8, example: u8) -> Input {
+        self.load_example(day, example)
+            .unwrap_or_else(|err| panic!("{err}"))
+    }
+
+    fn read(day: u8, path: PathBuf) -> Result<Input, InputError> {
+        let contents = match fs::read_to_string(&path) {
+            Ok(contents) => contents,
+            Err(source) => return Err(InputError::Read { day, path, source }),
+        };
+
+        Ok(Input::from_contents(contents))
+    }
+}
+
+/// A line of the input that could not be parsed.
+#[derive(Debug)]
+pub struct LineError<E> {
+    /// The 1-based line number.
+    pub line: usize,
+    pub text: String,
+    pub source: E,
+}
+
+impl<E: fmt::Display> fmt::Display for LineError<E> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "line {}: {}: {:?}", self.line, self.source, self.text)
+    }
+}
+
+impl<E: error::Error + 'static> error::Error for LineError<E> {
+    fn source(&self) -> Option<&(dyn error::Error + 'static)> {
+        Some(&self.source)
+    }
+}
+
+/// A puzzle input.
+///
+/// The contents are kept once, normalized to LF line endings without a BOM or trailing blank lines.
+/// Lines, rows and sections are all borrowed from them.
+pub struct Input {
+    text: String,
+}
+
+impl Input {
+    /// Create a new input from the contents of an input file.
+    ///
+    /// Lines may end in LF or CRLF, the final newline is optional and a leading UTF-8 BOM is
+    /// skipped. Trailing blank lines are dropped, blank lines in between are kept.
+    #[must_use]
+    pub fn from_contents(contents: impl Into<String>) -> Self {
+        let mut text = contents.into();
+
+        if text.starts_with('\u{feff}') {
+            text.drain(..'\u{feff}'.len_utf8());
+        }
+
+        if text.contains('\r') {
+            text = text.replace("\r\n", "\n");
+        }
+
+        let len = text.trim_end_matches('\n').len();
+        text.truncate(len);
+
+        Input { text }
+    }
+
+    /// Create a new input from a file anywhere on disk.
+    /// # Errors
+    /// Returns an error naming the path if the file can't be read.
+    pub fn from_path(path: impl Into<PathBuf>) -> Result<Self, InputError> {
+        let path = path.into();
+        match fs::read_to_string(&path) {
+            Ok(contents) => Ok(Input::from_contents(contents)),
+            Err(source) => Err(InputError::ReadPath { path, source }),
+        }
+    }
+
+    /// Parse the file at `path` with `parse`, or make a default `T` when there's no file yet, like
+    /// for files the runner writes such as confirmed answers.
+    /// # Errors
+    /// Returns an error if the file can't be read or `parse` rejects it.
+    pub(crate) fn parse_path_or_default<T: Default>(
+        path: impl Into<PathBuf>,
+        parse: impl FnOnce(&Self) -> Result<T, ParseError>,
+    ) -> Result<T, InputError> {
+        let path = path.into();
+        let input = match Self::from_path(&path) {
+            Ok(input) => input,
+            Err(InputError::ReadPath { source, .. })
+                if source.kind() == io::ErrorKind::NotFound =>
+            {
+                return Ok(T::default())
+            }
+            Err(err) => return Err(err),
+        };
+        parse(&input).map_err(|source| InputError::Parse { path, source })
+    }
+
+    /// Create a new input from anything that can be read, like a file or a socket.
+    /// # Errors
+    /// Returns an error if reading fails or the contents are not UTF-8.
+    pub fn from_reader(mut reader: impl Read) -> Result<Self, InputError> {
+        let mut contents = String::new();
+        reader
+            .read_to_string(&mut contents)
+            .map_err(|source| InputError::ReadStream { source })?;
+        Ok(Input::from_contents(contents))
+    }
+
+    /// Create a new input from everything on stdin.
+    /// # Errors
+    /// Returns an error if stdin can't be read or is not UTF-8.
+    pub fn from_stdin() -> Result<Self, InputError> {
+        Self::from_reader(io::stdin().lock())
+    }
+
+    /// The whole normalized input.
+    #[must_use]
+    pub fn text(&self) -> &str {
+        &self.text
+    }
+
+    /// The whole normalized input as bytes.
+    #[must_use]
+    pub fn bytes(&self) -> &[u8] {
+        self.text.as_bytes()
+    }
+
+    pub fn lines(&self) -> Lines<'_> {
+        self.text.lines()
+    }
+
+    /// The lines as bytes.
+    pub fn rows(&self) -> impl DoubleEndedIterator<Item = &[u8]> {
+        self.lines().map(str::as_bytes)
+    }
+
+    /// Parse every line into a `T`.
+    /// # Errors
+    /// Returns the first line that fails to parse.
+    pub fn parse_lines<T: FromStr>(&self) -> Result<Vec<T>, LineError<T::Err>> {
+        self.lines()
+            .enumerate()
+            .map(|(index, text)| {
+                text.parse().map_err(|source| LineError {
+                    line: index + 1,
+                    text: text.to_string(),
+                    source,
+                })
+            })
+            .collect()
+    }
+
+    /// The lines as a rectangular grid of chars.
+    /// # Errors
+    /// Returns an error naming the first row that is not as wide as the first row.
+    pub fn grid(&self) -> Result<Grid<char>, InputError> {
+        self.grid_with(|c| c)
+    }
+
+    /// The lines as a rectangular grid, mapping each char to a cell.
+    /// # Errors
+    /// Returns an error naming the first row that is not as wide as the first row.
+    pub fn grid_with<T>(&self, mut cell: impl FnMut(char) -> T) -> Result<Grid<T>, InputError> {
+        Grid::from_rows(
+            self.lines()
+                .map(|line| line.chars().map(&mut cell).collect::<Vec<T>>()),
+        )
+    }
+
+    /// The text of each section, where sections are separated by one or more blank lines.
+    #[must_use]
+    pub fn sections(&self) -> Vec<&str> {
+        self.text
+            .split("\n\n")
+            .map(|section| section.trim_matches('\n'))
+            .filter(|section| !section.is_empty())
+            .collect()
+    }
+
+    /// Like [`Input::sections`], for inputs made up of exactly `N` sections.
+    /// # Errors
+    /// Returns an error if the input does not have exactly `N` sections.
+    pub fn sections_exact<const N: usize>(&self) -> Result<[&str; N], InputError> {
+        let sections = self.sections();
+        let found = sections.len();
+        sections
+            .try_into()
+            .map_err(|_| InputError::SectionCount { expected: N, found })
+    }
+
+    /// Where `part` is in the input, if it was borrowed from it, e.g. from [`Input::lines`] or
+    /// [`Input::sections`].
+    #[must_use]
+    pub fn span_of(&self, part: &str) -> Option<Span> {
+        let start = (part.as_ptr() as usize).checked_sub(self.text.as_ptr() as usize)?;
+        if start + part.len() > self.text.len() {
+            return None;
+        }
+
+        let before = &self.text[..start];
+        let line_start = before.rfind('\n').map_or(0, |newline| newline + 1);
+        let part = part.split('\n').next().unwrap_or_default();
+        Some(Span {
+            line: before.matches('\n').count() + 1,
+            column: before[line_start..].chars().count() + 1,
+            len: part.chars().count(),
+        })
+    }
+
+    /// An error about `part` of the input, located with [`Input::span_of`].
+    #[must_use]
+    pub fn error_at(&self, part: &str, message: impl Into<String>) -> ParseError {
+        match self.span_of(part) {
+            Some(span) => ParseError::at(span, message),
+            None => ParseError::new(message),
+        }
+    }
+
+    /// Parse `part` of the input into a `T`.
+    /// # Errors
+    /// Returns an error pointing at `part` if it doesn't parse.
+    pub fn parse_at<T>(&self, part: &str) -> Result<T, ParseError>
+    where
+        T: FromStr,
+        T::Err: fmt::Display,
+    {
+        part.parse()
+            .map_err(|err| self.error_at(part, format!("invalid `{part}`: {err}")))
+    }
+
+    /// Parse all of `line` with `parser`, a line of the input or part of one.
+    /// # Errors
+    /// Returns an error saying what was expected, pointing at where it wasn't found.
+    pub fn parse_line<'a, T>(
+        &self,
+        line: &'a str,
+        parser: &impl Parser<'a, T>,
+    ) -> Result<T, ParseError> {
+        parser
+            .parse_complete(line)
+            .map_err(|failure| self.error_at(failure.at, failure.to_string()))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn loader() -> InputLoader {
+        InputLoader::new(2023, env!("CARGO_MANIFEST_DIR"))
+    }
+
+    #[test]
+    fn test_missing_input() {
+        let err = loader()
+            .load(99)
+            .err()
+            .expect("expect day 99 to have no input");
+        assert!(matches!(err, InputError::Read { day: 99, .. }));
+        assert!(err.to_string().starts_with("day 99: input missing at "));
+    }
+
+    #[test]
+    fn test_missing_example() {
+        let err = loader()
+            .load_example(99, 1)
+            .err()
+            .expect("expect day 99 to have no example");
+        assert!(matches!(err, InputError::Read { day: 99, .. }));
+    }
+
+    #[test]
+    fn test_loader_dir() {
+        let dir = env::temp_dir().join("aoc-core-loader-dir-test");
+        fs::create_dir_all(&dir).unwrap();
+        fs::write(dir.join("day1.txt"), "1\n2\n").unwrap();
+
+        let input = loader().dir(&dir).load(1).unwrap();
+
+        assert_eq!(input.lines().collect::<Vec<_>>(), ["1", "2"]);
+    }
+
+    #[test]
+    fn test_input_if_present() {
+        let dir = env::temp_dir().join("aoc-core-input-if-present-test");
+        fs::create_dir_all(&dir).unwrap();
+        fs::write(dir.join("day2.txt"), "3\n").unwrap();
+        let _ = fs::remove_file(dir.join("day3.txt"));
+        let loader = loader().dir(&dir);
+
+        let input = loader
+            .input_if_present(2)
+            .expect("expect day 2 to be present");
+        assert_eq!(input.lines().collect::<Vec<_>>(), ["3"]);
+        assert!(loader.input_if_present(3).is_none());
+    }
+
+    #[test]
+    fn test_default_dir_is_anchored_to_crate() {
+        let path = loader().path(1);
+        match env::var_os(INPUT_DIR_VAR) {
+            Some(dir) => assert_eq!(path, Path::new(&dir).join("2023/day1.txt")),
+            None => assert_eq!(
+                path,
+                Path::new(env!("CARGO_MANIFEST_DIR")).join("input/day1.txt")
+            ),
+        }
+    }
+
+    #[test]
+    fn test_examples() {
+        let dir = env::temp_dir().join("aoc-core-examples-test");
+        fs::create_dir_all(&dir).unwrap();
+        for name in [
+            "day1-example2.txt",
+            "day1-example10.txt",
+            "day1-example1.txt",
+        ] {
+            fs::write(dir.join(name), "1\n").unwrap();
+        }
+        fs::write(dir.join("day10-example1.txt"), "1\n").unwrap();
+
+        let loader = loader().example_dir(&dir);
+
+        assert_eq!(loader.examples(1).unwrap(), [1, 2, 10]);
+        assert!(loader.examples(99).unwrap().is_empty());
+        assert_eq!(loader.example(1, 10).text(), "1");
+    }
+
+    #[test]
+    fn test_lf() {
+        let input = Input::from_contents("1\n2\n");
+        assert_eq!(input.lines().collect::<Vec<_>>(), ["1", "2"]);
+    }
+
+    #[test]
+    fn test_crlf() {
+        let input = Input::from_contents("1\r\n2\r\n");
+        assert_eq!(input.lines().collect::<Vec<_>>(), ["1", "2"]);
+    }
+
+    #[test]
+    fn test_no_trailing_newline() {
+        let input = Input::from_contents("1\n2");
+        assert_eq!(input.lines().collect::<Vec<_>>(), ["1", "2"]);
+    }
+
+    #[test]
+    fn test_bom() {
+        let input = Input::from_contents("\u{feff}1\n2\n");
+        assert_eq!(input.lines().collect::<Vec<_>>(), ["1", "2"]);
+    }
+
+    #[test]
+    fn test_trailing_blank_lines() {
+        let input = Input::from_contents("1\n\n2\n\n\r\n\n");
+        assert_eq!(input.lines().collect::<Vec<_>>(), ["1", "", "2"]);
+    }
+
+    #[test]
+    fn test_empty() {
+        assert_eq!(Input::from_contents("").lines().count(), 0);
+        assert_eq!(Input::from_contents("\n").lines().count(), 0);
+    }
+
+    #[test]
+    fn test_sections() {
+        let input = Input::from_contents("1\n2\n\n3\n\n\n4\n");
+        let sections = input.sections();
+        assert_eq!(sections, ["1\n2", "3", "4"]);
+    }
+
+    #[test]
+    fn test_sections_exact() {
+        let input = Input::from_contents("1\n2\n\n3\n");
+        let [first, second] = input.sections_exact::<2>().unwrap();
+        assert_eq!(first, "1\n2");
+        assert_eq!(second, "3");
+
+        let err = input.sections_exact::<3>().err().unwrap();
+        assert!(matches!(
+            err,
+            InputError::SectionCount {
+                expected: 3,
+                found: 2
+            }
+        ));
+    }
+
+    #[test]
+    fn test_span_of() {
+        let input = Input::from_contents("move 1 from 2 to 3\nmove é from x to 1\n");
+        let line = input.lines().nth(1).unwrap();
+        assert_eq!(
+            input.span_of(&line[13..14]),
+            Some(Span {
+                line: 2,
+                column: 13,
+                len: 1
+            })
+        );
+        assert_eq!(input.span_of("x"), None);
+
+        let err = input.parse_at::<u32>(&line[13..14]).unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "line 2, column 13: invalid `x`: invalid digit found in string"
+        );
+    }
+
+    #[test]
+    fn test_parse_lines() {
+        let input = Input::from_contents("1\n2\n");
+        assert_eq!(input.parse_lines::<u32>().unwrap(), [1, 2]);
+
+        let input = Input::from_contents("1\nx\n3\n");
+        let err = input.parse_lines::<u32>().unwrap_err();
+        assert_eq!(err.line, 2);
+        assert_eq!(err.text, "x");
+        assert_eq!(
+            err.to_string(),
+            "line 2: invalid digit found in string: \"x\""
+        );
+    }
+
+    #[test]
+    fn test_grid() {
+        let input = Input::from_contents("12\n34\n");
+        assert_eq!(
+            input.grid().unwrap(),
+            Grid::from_rows([vec!['1', '2'], vec!['3', '4']]).unwrap()
+        );
+        assert_eq!(
+            input.grid_with(|c| c.to_digit(10).unwrap()).unwrap(),
+            Grid::from_rows([vec![1, 2], vec![3, 4]]).unwrap()
+        );
+    }
+
+    #[test]
+    fn test_ragged_grid() {
+        let input = Input::from_contents("12\n34\n5\n");
+        let err = input.grid().unwrap_err();
+        assert!(matches!(
+            err,
+            InputError::RaggedRow {
+                row: 3,
+                width: 1,
+                expected: 2
+            }
+        ));
+    }
+
+    #[test]
+    fn test_rows() {
+        let input = Input::from_contents("ab\r\ncd\r\n");
+        assert_eq!(input.text(), "ab\ncd");
+        assert_eq!(input.bytes(), b"ab\ncd");
+        assert_eq!(input.rows().collect::<Vec<_>>(), [b"ab", b"cd"]);
+    }
+
+    #[test]
+    fn test_from_reader() {
+        let input = Input::from_reader("1\r\n2\r\n".as_bytes()).unwrap();
+        assert_eq!(input.lines().collect::<Vec<_>>(), ["1", "2"]);
+    }
+
+    #[test]
+    fn test_from_path() {
+        let path = env::temp_dir().join("aoc-core-from-path-test.txt");
+        fs::write(&path, "1\n2\n").unwrap();
+        let input = Input::from_path(&path).unwrap();
+        assert_eq!(input.lines().collect::<Vec<_>>(), ["1", "2"]);
+
+        let err = Input::from_path("does/not/exist.txt").err().unwrap();
+        assert!(matches!(err, InputError::ReadPath { .. }));
+    }
+}
@@ -0,0 +1,367 @@
+//! Intervals of integers, and sets of them kept merged.
+
+use std::fmt;
+
+/// Integers that intervals can be made of.
+pub trait Discrete: Copy + Ord + fmt::Display {
+    /// The next integer, `None` at the maximum.
+    fn successor(self) -> Option<Self>;
+
+    /// The number of integers from `from` up to but not including `to`, which must not be less.
+    fn distance(from: Self, to: Self) -> u64;
+}
+
+macro_rules! impl_discrete {
+    ($($ty:ty),*) => {
+        $(
+            impl Discrete for $ty {
+                fn successor(self) -> Option<Self> {
+                    self.checked_add(1)
+                }
+
+                // Every supported type fits in an `i128`, and the span of any of them in a `u64`.
+                fn distance(from: Self, to: Self) -> u64 {
+                    (to as i128 - from as i128) as u64
+                }
+            }
+        )*
+    };
+}
+
+impl_discrete!(u8, u16, u32, u64, usize, i8, i16, i32, i64, isize);
+
+/// The integers from `start` up to but not including `end`. Empty when `end` is not past `start`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Interval<T> {
+    start: T,
+    end: T,
+}
+
+impl<T: Discrete> Interval<T> {
+    /// The interval from `start` up to but not including `end`.
+    #[must_use]
+    pub fn new(start: T, end: T) -> Self {
+        Self { start, end }
+    }
+
+    /// The interval from `first` up to and including `last`, like puzzles tend to write ranges.
+    /// # Panics
+    /// Panics if `last` is the maximum of `T`, as the end would not fit. See
+    /// [`Interval::try_inclusive`] for ranges from the input.
+    #[must_use]
+    pub fn inclusive(first: T, last: T) -> Self {
+        Self::try_inclusive(first, last)
+            .expect("expect the last of an inclusive interval below the maximum")
+    }
+
+    /// Like [`Interval::inclusive`], `None` if `last` is the maximum of `T`.
+    #[must_use]
+    pub fn try_inclusive(first: T, last: T) -> Option<Self> {
+        Some(Self::new(first, last.successor()?))
+    }
+
+    #[must_use]
+    pub fn start(&self) -> T {
+        self.start
+    }
+
+    /// The first integer past the interval.
+    #[must_use]
+    pub fn end(&self) -> T {
+        self.end
+    }
+
+    #[must_use]
+    pub fn is_empty(&self) -> bool {
+        self.end <= self.start
+    }
+
+    /// The number of integers in the interval.
+    #[must_use]
+    pub fn len(&self) -> u64 {
+        if self.is_empty() {
+            0
+        } else {
+            T::distance(self.start, self.end)
+        }
+    }
+
+    #[must_use]
+    pub fn contains(&self, value: T) -> bool {
+        self.start <= value && value < self.end
+    }
+
+    /// Whether every integer of `other` is in this interval. An empty interval is in any other.
+    #[must_use]
+    pub fn covers(&self, other: &Self) -> bool {
+        other.is_empty() || (self.start <= other.start && other.end <= self.end)
+    }
+
+    /// Whether the intervals have any integer in common.
+    #[must_use]
+    pub fn overlaps(&self, other: &Self) -> bool {
+        self.intersection(other).is_some()
+    }
+
+    /// The integers in both intervals, `None` if there are none.
+    #[must_use]
+    pub fn intersection(&self, other: &Self) -> Option<Self> {
+        let intersection = Self::new(self.start.max(other.start), self.end.min(other.end));
+        (!intersection.is_empty()).then_some(intersection)
+    }
+
+    /// The integers in this interval but not in `other`: up to two intervals, on either side of it.
+    pub fn difference(&self, other: &Self) -> impl Iterator<Item = Self> {
+        let (before, after) = if self.overlaps(other) {
+            (
+                Self::new(self.start, other.start),
+                Self::new(other.end, self.end),
+            )
+        } else {
+            (*self, Self::new(self.end, self.end))
+        };
+        [before, after]
+            .into_iter()
+            .filter(|interval| !interval.is_empty())
+    }
+}
+
+impl<T: Discrete> fmt::Display for Interval<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "[{}, {})", self.start, self.end)
+    }
+}
+
+/// A set of integers kept as sorted intervals that neither overlap nor touch.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct IntervalSet<T> {
+    intervals: Vec<Interval<T>>,
+}
+
+impl<T> Default for IntervalSet<T> {
+    fn default() -> Self {
+        Self {
+            intervals: Vec::new(),
+        }
+    }
+}
+
+impl<T: Discrete> IntervalSet<T> {
+    #[must_use]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Add every integer of `interval`, merging it with the intervals it overlaps or touches.
+    pub fn insert(&mut self, interval: Interval<T>) {
+        if interval.is_empty() {
+            return;
+        }
+        // The intervals before `first` end before `interval` starts and those from `last` on start
+        // after it ends, the ones in between merge with it.
+        let first = self
+            .intervals
+            .partition_point(|other| other.end < interval.start);
+        let last = self
+            .intervals
+            .partition_point(|other| other.start <= interval.end);
+
+        let merged = self.intervals[first..last]
+            .iter()
+            .fold(interval, |merged, other| {
+                Interval::new(merged.start.min(other.start), merged.end.max(other.end))
+            });
+        self.intervals.splice(first..last, [merged]);
+    }
+
+    /// Take out every integer of `interval`.
+    pub fn remove(&mut self, interval: Interval<T>) {
+        if interval.is_empty() {
+            return;
+        }
+        let first = self
+            .intervals
+            .partition_point(|other| other.end <= interval.start);
+        let last = self
+            .intervals
+            .partition_point(|other| other.start < interval.end);
+
+        let remaining = self.intervals[first..last]
+            .iter()
+            .flat_map(|other| other.difference(&interval))
+            .collect::<Vec<_>>();
+        self.intervals.splice(first..last, remaining);
+    }
+
+    /// Add every integer of `other`.
+    pub fn union(&mut self, other: &Self) {
+        for &interval in &other.intervals {
+            self.insert(interval);
+        }
+    }
+
+    /// Take out every integer of `other`.
+    pub fn subtract(&mut self, other: &Self) {
+        for &interval in &other.intervals {
+            self.remove(interval);
+        }
+    }
+
+    #[must_use]
+    pub fn contains(&self, value: T) -> bool {
+        let index = self
+            .intervals
+            .partition_point(|interval| interval.end <= value);
+        self.intervals
+            .get(index)
+            .is_some_and(|interval| interval.contains(value))
+    }
+
+    #[must_use]
+    pub fn is_empty(&self) -> bool {
+        self.intervals.is_empty()
+    }
+
+    /// The number of integers in the set.
+    #[must_use]
+    pub fn len(&self) -> u64 {
+        self.intervals.iter().map(Interval::len).sum()
+    }
+
+    /// The intervals in order.
+    pub fn iter(&self) -> impl Iterator<Item = &Interval<T>> {
+        self.intervals.iter()
+    }
+}
+
+impl<T: Discrete> FromIterator<Interval<T>> for IntervalSet<T> {
+    fn from_iter<I: IntoIterator<Item = Interval<T>>>(iter: I) -> Self {
+        let mut set = Self::new();
+        for interval in iter {
+            set.insert(interval);
+        }
+        set
+    }
+}
+
+impl<T: Discrete> Extend<Interval<T>> for IntervalSet<T> {
+    fn extend<I: IntoIterator<Item = Interval<T>>>(&mut self, iter: I) {
+        for interval in iter {
+            self.insert(interval);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_interval() {
+        let interval = Interval::inclusive(2, 4);
+        assert_eq!(interval, Interval::new(2, 5));
+        assert_eq!(interval.len(), 3);
+        assert!(interval.contains(4));
+        assert!(!interval.contains(5));
+        assert!(Interval::new(3, 3).is_empty());
+        assert_eq!(Interval::new(5, 3).len(), 0);
+        assert_eq!(Interval::new(i64::MIN, i64::MAX).len(), u64::MAX);
+        assert_eq!(interval.to_string(), "[2, 5)");
+        assert_eq!(Interval::try_inclusive(2, 4), Some(interval));
+        assert_eq!(Interval::try_inclusive(0, u8::MAX), None);
+
+        assert!(Interval::inclusive(2, 8).covers(&Interval::inclusive(3, 7)));
+        assert!(!Interval::inclusive(3, 7).covers(&Interval::inclusive(2, 8)));
+        assert!(Interval::inclusive(5, 7).overlaps(&Interval::inclusive(7, 9)));
+        assert!(!Interval::inclusive(2, 4).overlaps(&Interval::inclusive(5, 7)));
+    }
+
+    #[test]
+    fn test_intersection_and_difference() {
+        let a = Interval::new(0, 10);
+        assert_eq!(
+            a.intersection(&Interval::new(5, 15)),
+            Some(Interval::new(5, 10))
+        );
+        assert_eq!(a.intersection(&Interval::new(10, 15)), None);
+
+        let difference = |b| a.difference(&b).collect::<Vec<_>>();
+        assert_eq!(
+            difference(Interval::new(3, 5)),
+            [Interval::new(0, 3), Interval::new(5, 10)]
+        );
+        assert_eq!(difference(Interval::new(-5, 5)), [Interval::new(5, 10)]);
+        assert_eq!(difference(Interval::new(20, 30)), [a]);
+        assert!(difference(Interval::new(-1, 11)).is_empty());
+    }
+
+    #[test]
+    fn test_set_insert() {
+        let mut set = [
+            Interval::new(10, 12),
+            Interval::new(0, 2),
+            Interval::new(5, 7),
+        ]
+        .into_iter()
+        .collect::<IntervalSet<i32>>();
+        assert_eq!(set.len(), 6);
+
+        // Touching intervals merge too.
+        set.insert(Interval::new(2, 5));
+        set.insert(Interval::new(11, 20));
+        assert_eq!(
+            set.iter().copied().collect::<Vec<_>>(),
+            [Interval::new(0, 7), Interval::new(10, 20)]
+        );
+        assert!(set.contains(6));
+        assert!(!set.contains(7));
+        assert!(set.contains(19));
+        assert!(!set.contains(-1));
+    }
+
+    #[test]
+    fn test_set_len_of_overlapping() {
+        // The assignments of the 2022 day 4 example cover sections 2 to 9.
+        let set = [
+            (2, 4),
+            (6, 8),
+            (2, 3),
+            (4, 5),
+            (5, 7),
+            (7, 9),
+            (2, 8),
+            (3, 7),
+        ]
+        .into_iter()
+        .map(|(first, last)| Interval::inclusive(first, last))
+        .collect::<IntervalSet<u32>>();
+        assert_eq!(set.len(), 8);
+        assert_eq!(
+            set.iter().copied().collect::<Vec<_>>(),
+            [Interval::new(2, 10)]
+        );
+    }
+
+    #[test]
+    fn test_set_remove() {
+        let mut set = IntervalSet::new();
+        set.insert(Interval::new(0, 10));
+        set.insert(Interval::new(20, 30));
+
+        set.remove(Interval::new(5, 25));
+        assert_eq!(
+            set.iter().copied().collect::<Vec<_>>(),
+            [Interval::new(0, 5), Interval::new(25, 30)]
+        );
+
+        let mut other = IntervalSet::new();
+        other.insert(Interval::new(1, 2));
+        set.subtract(&other);
+        assert_eq!(set.len(), 9);
+
+        set.union(&other);
+        assert_eq!(set.len(), 10);
+        set.remove(Interval::new(-100, 100));
+        assert!(set.is_empty());
+    }
+}
@@ -0,0 +1,29 @@
+//! Shared toolkit for the Advent of Code crates of every year.
+
+pub mod allocations;
+pub mod answer;
+pub mod answers;
+pub mod bench;
+pub mod diagnostic;
+pub mod grid;
+pub mod input;
+pub mod interval;
+pub mod parser;
+pub mod point;
+pub mod search;
+pub mod solution;
+pub mod timing;
+
+pub use allocations::{measure, Allocations, Bytes, CountingAllocator};
+pub use answer::Answer;
+pub use answers::{assert_answers, verify, Answers, Check, Status};
+pub use bench::{Baseline, Change, Sample, Step};
+pub use diagnostic::{ParseError, Span};
+pub use grid::Grid;
+pub use input::{Input, InputError, InputLoader, LineError, INPUT_DIR_VAR};
+pub use interval::{Interval, IntervalSet};
+pub use parser::{ident, literal, separated, signed, unsigned, ws, Failure, ParseResult, Parser};
+pub use point::{Direction, Point, Vector};
+pub use search::{astar, bfs, dijkstra, Paths};
+pub use solution::{Day, ParsePartError, Parsed, Part, Registry, Solution};
+pub use timing::{sample_runs, time_runs, Elapsed, Timings};
@@ -0,0 +1,329 @@
+//! Small parser combinators for the formats of puzzle lines.
+//!
+//! A format is declared by putting parsers in a tuple, which parses them one after the other:
+//!
+//! ```
+//! use aoc_core::{literal, unsigned, Parser};
+//!
+//! let instruction = (
+//!     literal("move "),
+//!     unsigned::<u32>(),
+//!     literal(" from "),
+//!     unsigned::<u32>(),
+//!     literal(" to "),
+//!     unsigned::<u32>(),
+//! )
+//!     .map(|(_, count, _, from, _, to)| (count, from, to));
+//!
+//! assert_eq!(instruction.parse_complete("move 1 from 2 to 3"), Ok((1, 2, 3)));
+//! let err = instruction.parse_complete("move 1 from 2").unwrap_err();
+//! assert_eq!(err.to_string(), "expected ` to `");
+//! ```
+//!
+//! Every failure borrows the text it's about from what was parsed, so [`Input::parse_line`]
+//! can point at it.
+//!
+//! [`Input::parse_line`]: crate::Input::parse_line
+
+use std::{any, error, fmt, str::FromStr};
+
+/// What a parser expected to find, and where in the text it didn't.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Failure<'a> {
+    /// The text the failure is about, empty when it's about a position, such as the end of a line.
+    pub at: &'a str,
+    /// What should have been there, such as "` to `" or "an identifier".
+    pub expected: String,
+}
+
+impl<'a> Failure<'a> {
+    fn new(at: &'a str, expected: impl Into<String>) -> Self {
+        Self {
+            at,
+            expected: expected.into(),
+        }
+    }
+}
+
+impl fmt::Display for Failure<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "expected {}", self.expected)
+    }
+}
+
+impl error::Error for Failure<'_> {}
+
+/// What a parser found at the start of the text, with the text after it.
+pub type ParseResult<'a, T> = Result<(T, &'a str), Failure<'a>>;
+
+/// Something that parses a `T` off the start of a text borrowed for `'a`. Implemented by closures
+/// and by tuples of parsers, which parse their parsers in order.
+pub trait Parser<'a, T> {
+    /// Parse a `T` off the start of `text`.
+    /// # Errors
+    /// Returns what was expected where `text` didn't match.
+    fn parse(&self, text: &'a str) -> ParseResult<'a, T>;
+
+    /// Parse all of `text` into a `T`.
+    /// # Errors
+    /// Returns what was expected where `text` didn't match, or the text left over.
+    fn parse_complete(&self, text: &'a str) -> Result<T, Failure<'a>> {
+        match self.parse(text)? {
+            (value, "") => Ok(value),
+            (_, rest) => Err(Failure::new(rest, "the end of the line")),
+        }
+    }
+
+    /// A parser of whatever `f` makes of what this one parses.
+    fn map<U>(self, f: impl Fn(T) -> U) -> impl Parser<'a, U>
+    where
+        Self: Sized,
+    {
+        move |text: &'a str| {
+            let (value, rest) = self.parse(text)?;
+            Ok((f(value), rest))
+        }
+    }
+
+    /// A parser that also fails when `accept` rejects what this one parses, expecting `expected`
+    /// at the text it parsed.
+    fn verify(self, accept: impl Fn(&T) -> bool, expected: impl fmt::Display) -> impl Parser<'a, T>
+    where
+        Self: Sized,
+    {
+        move |text: &'a str| {
+            let (value, rest) = self.parse(text)?;
+            if accept(&value) {
+                Ok((value, rest))
+            } else {
+                Err(Failure::new(consumed(text, rest), expected.to_string()))
+            }
+        }
+    }
+}
+
+impl<'a, T, F> Parser<'a, T> for F
+where
+    F: Fn(&'a str) -> ParseResult<'a, T>,
+{
+    fn parse(&self, text: &'a str) -> ParseResult<'a, T> {
+        self(text)
+    }
+}
+
+macro_rules! impl_tuple_parser {
+    ($(($parser:ident, $value:ident)),+) => {
+        impl<'a, $($value, $parser: Parser<'a, $value>),+> Parser<'a, ($($value,)+)> for ($($parser,)+) {
+            #[allow(non_snake_case)]
+            fn parse(&self, text: &'a str) -> ParseResult<'a, ($($value,)+)> {
+                let ($($parser,)+) = self;
+                let rest = text;
+                $(let ($value, rest) = $parser.parse(rest)?;)+
+                Ok((($($value,)+), rest))
+            }
+        }
+    };
+}
+
+impl_tuple_parser!((P1, T1), (P2, T2));
+impl_tuple_parser!((P1, T1), (P2, T2), (P3, T3));
+impl_tuple_parser!((P1, T1), (P2, T2), (P3, T3), (P4, T4));
+impl_tuple_parser!((P1, T1), (P2, T2), (P3, T3), (P4, T4), (P5, T5));
+impl_tuple_parser!((P1, T1), (P2, T2), (P3, T3), (P4, T4), (P5, T5), (P6, T6));
+impl_tuple_parser!(
+    (P1, T1),
+    (P2, T2),
+    (P3, T3),
+    (P4, T4),
+    (P5, T5),
+    (P6, T6),
+    (P7, T7)
+);
+impl_tuple_parser!(
+    (P1, T1),
+    (P2, T2),
+    (P3, T3),
+    (P4, T4),
+    (P5, T5),
+    (P6, T6),
+    (P7, T7),
+    (P8, T8)
+);
+
+/// The part of `text` before `rest`, which must be a suffix of it.
+fn consumed<'a>(text: &'a str, rest: &str) -> &'a str {
+    &text[..text.len() - rest.len()]
+}
+
+/// Split `text` after the chars at its start that `matches` accepts.
+fn take_while(text: &str, matches: impl Fn(char) -> bool) -> (&str, &str) {
+    text.split_at(text.find(|c| !matches(c)).unwrap_or(text.len()))
+}
+
+/// Parses exactly `expected`.
+pub fn literal<'a>(expected: &'static str) -> impl Parser<'a, &'a str> {
+    move |text: &'a str| match text.strip_prefix(expected) {
+        Some(rest) => Ok((consumed(text, rest), rest)),
+        None => Err(Failure::new(&text[..0], format!("`{expected}`"))),
+    }
+}
+
+/// Parses digits into a `T`.
+pub fn unsigned<'a, T>() -> impl Parser<'a, T>
+where
+    T: FromStr,
+{
+    move |text: &'a str| {
+        let (digits, rest) = take_while(text, |c| c.is_ascii_digit());
+        number(digits, rest, "an unsigned integer")
+    }
+}
+
+/// Parses digits with an optional `-` or `+` before them into a `T`.
+pub fn signed<'a, T>() -> impl Parser<'a, T>
+where
+    T: FromStr,
+{
+    move |text: &'a str| {
+        let unsigned = text.strip_prefix(['-', '+']).unwrap_or(text);
+        let (digits, rest) = take_while(unsigned, |c| c.is_ascii_digit());
+        if digits.is_empty() {
+            return Err(Failure::new(&text[..0], "an integer"));
+        }
+        number(consumed(text, rest), rest, "an integer")
+    }
+}
+
+fn number<'a, T: FromStr>(digits: &'a str, rest: &'a str, kind: &str) -> ParseResult<'a, T> {
+    if digits.is_empty() {
+        return Err(Failure::new(digits, kind));
+    }
+    match digits.parse() {
+        Ok(value) => Ok((value, rest)),
+        // Digits only fail to parse when they don't fit.
+        Err(_) => Err(Failure::new(
+            digits,
+            format!("{kind} that fits in a `{}`", any::type_name::<T>()),
+        )),
+    }
+}
+
+/// Parses a word of ASCII letters, digits and `_` that doesn't start with a digit.
+pub fn ident<'a>() -> impl Parser<'a, &'a str> {
+    move |text: &'a str| {
+        if !text.starts_with(|c: char| c.is_ascii_alphabetic() || c == '_') {
+            return Err(Failure::new(&text[..0], "an identifier"));
+        }
+        Ok(take_while(text, |c| c.is_ascii_alphanumeric() || c == '_'))
+    }
+}
+
+/// Parses one or more of `item`, with `separator` between them.
+pub fn separated<'a, T, S>(
+    item: impl Parser<'a, T>,
+    separator: impl Parser<'a, S>,
+) -> impl Parser<'a, Vec<T>> {
+    move |text: &'a str| {
+        let (first, mut rest) = item.parse(text)?;
+        let mut items = vec![first];
+        // Once a separator matched, an item has to follow it.
+        while let Ok((_, after)) = separator.parse(rest) {
+            let (next, after) = item.parse(after)?;
+            items.push(next);
+            rest = after;
+        }
+        Ok((items, rest))
+    }
+}
+
+/// Parses `inner` with any whitespace before and after it.
+pub fn ws<'a, T>(inner: impl Parser<'a, T>) -> impl Parser<'a, T> {
+    move |text: &'a str| {
+        let (value, rest) = inner.parse(text.trim_start())?;
+        Ok((value, rest.trim_start()))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_literal_and_ident() {
+        assert_eq!(literal("Game ").parse("Game 1"), Ok(("Game ", "1")));
+        let err = literal("Game ").parse("Gam 1").unwrap_err();
+        assert_eq!((err.at, err.to_string().as_str()), ("", "expected `Game `"));
+
+        assert_eq!(ident().parse("blue, 4"), Ok(("blue", ", 4")));
+        assert_eq!(ident().parse("_x1 y"), Ok(("_x1", " y")));
+        assert_eq!(
+            ident().parse("1x").unwrap_err().to_string(),
+            "expected an identifier"
+        );
+    }
+
+    #[test]
+    fn test_numbers() {
+        assert_eq!(unsigned::<u32>().parse("42 red"), Ok((42, " red")));
+        assert_eq!(
+            unsigned::<u32>().parse("-4").unwrap_err().to_string(),
+            "expected an unsigned integer"
+        );
+        let err = unsigned::<u8>().parse("256,").unwrap_err();
+        assert_eq!(err.at, "256");
+        assert_eq!(
+            err.to_string(),
+            "expected an unsigned integer that fits in a `u8`"
+        );
+
+        assert_eq!(signed::<i64>().parse("-12,"), Ok((-12, ",")));
+        assert_eq!(signed::<i64>().parse("+3"), Ok((3, "")));
+        assert_eq!(signed::<i64>().parse("7"), Ok((7, "")));
+        assert!(signed::<i64>().parse("-x").is_err());
+    }
+
+    #[test]
+    fn test_sequences() {
+        let range = (unsigned::<u32>(), literal("-"), unsigned::<u32>())
+            .map(|(first, _, last)| (first, last))
+            .verify(
+                |(first, last)| first <= last,
+                "a range that ends after it starts",
+            );
+        let pair = (range, literal(","), ws(unsigned::<u32>()));
+
+        assert_eq!(pair.parse_complete("2-4, 6 "), Ok(((2, 4), ",", 6)));
+
+        let text = "4-2,6";
+        let err = pair.parse_complete(text).unwrap_err();
+        assert_eq!(err.at, &text[..3]);
+        assert_eq!(
+            err.to_string(),
+            "expected a range that ends after it starts"
+        );
+
+        let err = pair.parse_complete("2-4,6 7").unwrap_err();
+        assert_eq!(
+            (err.at, err.expected.as_str()),
+            ("7", "the end of the line")
+        );
+    }
+
+    #[test]
+    fn test_separated() {
+        let cubes = separated(
+            (unsigned::<u32>(), literal(" "), ident()).map(|(count, _, color)| (count, color)),
+            literal(", "),
+        );
+        let sets = separated(cubes, literal("; "));
+        assert_eq!(
+            sets.parse_complete("3 blue, 4 red; 2 green"),
+            Ok(vec![vec![(3, "blue"), (4, "red")], vec![(2, "green")]])
+        );
+
+        // A separator has to be followed by another item.
+        let err = sets.parse_complete("3 blue, red").unwrap_err();
+        assert_eq!(err.to_string(), "expected an unsigned integer");
+        assert!(sets.parse("").is_err());
+    }
+}
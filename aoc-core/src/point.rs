@@ -0,0 +1,342 @@
+//! Positions, the steps between them and the directions they go in, on a grid or plane.
+//!
+//! `x` grows to the right and `y` grows down, like the columns and lines of an input. So turning
+//! right goes clockwise as seen on screen, from up to right to down to left.
+
+use std::{
+    fmt,
+    num::TryFromIntError,
+    ops::{Add, AddAssign, Mul, Neg, Sub, SubAssign},
+};
+
+/// A position.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct Point {
+    pub x: i64,
+    pub y: i64,
+}
+
+impl Point {
+    pub const ORIGIN: Self = Self::new(0, 0);
+
+    #[must_use]
+    pub const fn new(x: i64, y: i64) -> Self {
+        Self { x, y }
+    }
+
+    /// The number of orthogonal steps to `other`.
+    #[must_use]
+    pub fn manhattan(self, other: Self) -> u64 {
+        (other - self).manhattan_len()
+    }
+
+    /// The number of steps to `other` when diagonal steps are allowed too.
+    #[must_use]
+    pub fn chebyshev(self, other: Self) -> u64 {
+        (other - self).chebyshev_len()
+    }
+}
+
+impl fmt::Display for Point {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "({}, {})", self.x, self.y)
+    }
+}
+
+/// The point at a column and row of a grid. Fails only for indices beyond `i64::MAX`.
+impl TryFrom<(usize, usize)> for Point {
+    type Error = TryFromIntError;
+
+    fn try_from((x, y): (usize, usize)) -> Result<Self, Self::Error> {
+        Ok(Self::new(x.try_into()?, y.try_into()?))
+    }
+}
+
+/// The column and row of a grid at a point. Fails for points left of or above the grid.
+impl TryFrom<Point> for (usize, usize) {
+    type Error = TryFromIntError;
+
+    fn try_from(point: Point) -> Result<Self, Self::Error> {
+        Ok((point.x.try_into()?, point.y.try_into()?))
+    }
+}
+
+/// A step from one point to another.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct Vector {
+    pub dx: i64,
+    pub dy: i64,
+}
+
+impl Vector {
+    pub const ZERO: Self = Self::new(0, 0);
+
+    #[must_use]
+    pub const fn new(dx: i64, dy: i64) -> Self {
+        Self { dx, dy }
+    }
+
+    /// The vector turned a quarter counterclockwise.
+    #[must_use]
+    pub const fn rotate_left(self) -> Self {
+        Self::new(self.dy, -self.dx)
+    }
+
+    /// The vector turned a quarter clockwise.
+    #[must_use]
+    pub const fn rotate_right(self) -> Self {
+        Self::new(-self.dy, self.dx)
+    }
+
+    /// The length when only orthogonal steps are allowed.
+    #[must_use]
+    pub fn manhattan_len(self) -> u64 {
+        self.dx.unsigned_abs() + self.dy.unsigned_abs()
+    }
+
+    /// The length when diagonal steps are allowed too.
+    #[must_use]
+    pub fn chebyshev_len(self) -> u64 {
+        self.dx.unsigned_abs().max(self.dy.unsigned_abs())
+    }
+}
+
+impl fmt::Display for Vector {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "<{}, {}>", self.dx, self.dy)
+    }
+}
+
+impl Add<Vector> for Point {
+    type Output = Self;
+
+    fn add(self, rhs: Vector) -> Self {
+        Self::new(self.x + rhs.dx, self.y + rhs.dy)
+    }
+}
+
+impl AddAssign<Vector> for Point {
+    fn add_assign(&mut self, rhs: Vector) {
+        *self = *self + rhs;
+    }
+}
+
+impl Sub<Vector> for Point {
+    type Output = Self;
+
+    fn sub(self, rhs: Vector) -> Self {
+        self + -rhs
+    }
+}
+
+impl SubAssign<Vector> for Point {
+    fn sub_assign(&mut self, rhs: Vector) {
+        *self = *self - rhs;
+    }
+}
+
+/// The vector from `rhs` to `self`.
+impl Sub for Point {
+    type Output = Vector;
+
+    fn sub(self, rhs: Self) -> Vector {
+        Vector::new(self.x - rhs.x, self.y - rhs.y)
+    }
+}
+
+impl Add for Vector {
+    type Output = Self;
+
+    fn add(self, rhs: Self) -> Self {
+        Self::new(self.dx + rhs.dx, self.dy + rhs.dy)
+    }
+}
+
+impl AddAssign for Vector {
+    fn add_assign(&mut self, rhs: Self) {
+        *self = *self + rhs;
+    }
+}
+
+impl Sub for Vector {
+    type Output = Self;
+
+    fn sub(self, rhs: Self) -> Self {
+        self + -rhs
+    }
+}
+
+impl SubAssign for Vector {
+    fn sub_assign(&mut self, rhs: Self) {
+        *self = *self - rhs;
+    }
+}
+
+impl Neg for Vector {
+    type Output = Self;
+
+    fn neg(self) -> Self {
+        Self::new(-self.dx, -self.dy)
+    }
+}
+
+impl Mul<i64> for Vector {
+    type Output = Self;
+
+    fn mul(self, rhs: i64) -> Self {
+        Self::new(self.dx * rhs, self.dy * rhs)
+    }
+}
+
+/// One of the 8 directions to a neighboring cell.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum Direction {
+    Up,
+    UpRight,
+    Right,
+    DownRight,
+    Down,
+    DownLeft,
+    Left,
+    UpLeft,
+}
+
+impl Direction {
+    /// The 4 orthogonal directions, clockwise from up.
+    pub const ORTHOGONAL: [Self; 4] = [Self::Up, Self::Right, Self::Down, Self::Left];
+
+    /// All 8 directions, diagonals included, clockwise from up.
+    pub const ALL: [Self; 8] = [
+        Self::Up,
+        Self::UpRight,
+        Self::Right,
+        Self::DownRight,
+        Self::Down,
+        Self::DownLeft,
+        Self::Left,
+        Self::UpLeft,
+    ];
+
+    /// Turn by `eighths` of a full turn, clockwise.
+    const fn turn(self, eighths: usize) -> Self {
+        Self::ALL[(self as usize + eighths) % 8]
+    }
+
+    /// The direction a quarter turn counterclockwise.
+    #[must_use]
+    pub const fn turn_left(self) -> Self {
+        self.turn(6)
+    }
+
+    /// The direction a quarter turn clockwise.
+    #[must_use]
+    pub const fn turn_right(self) -> Self {
+        self.turn(2)
+    }
+
+    /// The opposite direction.
+    #[must_use]
+    pub const fn reverse(self) -> Self {
+        self.turn(4)
+    }
+
+    /// Whether the direction goes along a row or column.
+    #[must_use]
+    pub const fn is_orthogonal(self) -> bool {
+        matches!(self, Self::Up | Self::Right | Self::Down | Self::Left)
+    }
+
+    /// The step to the neighboring cell in this direction.
+    #[must_use]
+    pub const fn vector(self) -> Vector {
+        match self {
+            Self::Up => Vector::new(0, -1),
+            Self::UpRight => Vector::new(1, -1),
+            Self::Right => Vector::new(1, 0),
+            Self::DownRight => Vector::new(1, 1),
+            Self::Down => Vector::new(0, 1),
+            Self::DownLeft => Vector::new(-1, 1),
+            Self::Left => Vector::new(-1, 0),
+            Self::UpLeft => Vector::new(-1, -1),
+        }
+    }
+}
+
+impl From<Direction> for Vector {
+    fn from(direction: Direction) -> Self {
+        direction.vector()
+    }
+}
+
+impl Add<Direction> for Point {
+    type Output = Self;
+
+    fn add(self, rhs: Direction) -> Self {
+        self + rhs.vector()
+    }
+}
+
+impl AddAssign<Direction> for Point {
+    fn add_assign(&mut self, rhs: Direction) {
+        *self += rhs.vector();
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_ops() {
+        let mut point = Point::new(2, 3);
+        assert_eq!(point + Vector::new(-3, 1), Point::new(-1, 4));
+        assert_eq!(point - Vector::new(1, 1), Point::new(1, 2));
+        assert_eq!(Point::new(5, 1) - point, Vector::new(3, -2));
+        assert_eq!(Vector::new(1, 2) * 3 - Vector::new(1, 1), Vector::new(2, 5));
+
+        point += Direction::Up;
+        point -= Vector::new(2, 0);
+        assert_eq!(point, Point::new(0, 2));
+    }
+
+    #[test]
+    fn test_distances() {
+        let (a, b) = (Point::new(1, -2), Point::new(-3, 5));
+        assert_eq!(a.manhattan(b), 11);
+        assert_eq!(b.manhattan(a), 11);
+        assert_eq!(a.chebyshev(b), 7);
+        assert_eq!(Point::ORIGIN.chebyshev(Point::ORIGIN), 0);
+    }
+
+    #[test]
+    fn test_directions() {
+        assert_eq!(Direction::Up.turn_right(), Direction::Right);
+        assert_eq!(Direction::Up.turn_left(), Direction::Left);
+        assert_eq!(Direction::UpLeft.turn_right(), Direction::UpRight);
+        assert_eq!(Direction::DownRight.reverse(), Direction::UpLeft);
+        assert_eq!(
+            Direction::ALL.iter().filter(|d| d.is_orthogonal()).count(),
+            4
+        );
+
+        for direction in Direction::ALL {
+            assert_eq!(
+                direction.turn_right().vector(),
+                direction.vector().rotate_right()
+            );
+            assert_eq!(
+                direction.turn_left().vector(),
+                direction.vector().rotate_left()
+            );
+            assert_eq!(direction.reverse().vector(), -direction.vector());
+        }
+    }
+
+    #[test]
+    fn test_grid_indices() {
+        assert_eq!(Point::try_from((3, 4)), Ok(Point::new(3, 4)));
+        assert_eq!(<(usize, usize)>::try_from(Point::new(3, 4)), Ok((3, 4)));
+        assert!(<(usize, usize)>::try_from(Point::new(-1, 4)).is_err());
+        assert!(Point::try_from((usize::MAX, 0)).is_err());
+    }
+}
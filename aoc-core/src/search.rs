@@ -0,0 +1,319 @@
+//! Shortest paths over graphs that are only known through the neighbors of each node.
+//!
+//! Nodes are anything hashable, such as a [`Point`](crate::Point) of a grid or a whole puzzle
+//! state. Each search starts from any number of nodes and stops at the first node `is_goal`
+//! accepts, or explores everything it can reach when there is none, e.g. with `|_| false`.
+
+use std::{
+    cmp::Ordering,
+    collections::{hash_map::Entry, BinaryHeap, HashMap, VecDeque},
+    hash::Hash,
+    ops::Add,
+};
+
+/// What a search found: the cheapest known cost of every node it reached and the node it reached
+/// each from, so paths can be traced back to a start.
+#[derive(Debug, Clone)]
+pub struct Paths<N, C> {
+    costs: HashMap<N, C>,
+    predecessors: HashMap<N, N>,
+    goal: Option<N>,
+}
+
+impl<N: Eq + Hash + Clone, C: Copy> Paths<N, C> {
+    fn new(starts: impl IntoIterator<Item = N>, zero: C) -> Self {
+        Self {
+            costs: starts.into_iter().map(|start| (start, zero)).collect(),
+            predecessors: HashMap::new(),
+            goal: None,
+        }
+    }
+
+    /// The goal the search stopped at, `None` if it reached none.
+    #[must_use]
+    pub fn goal(&self) -> Option<&N> {
+        self.goal.as_ref()
+    }
+
+    /// The cost of the cheapest path to `node`, `None` if it wasn't reached.
+    #[must_use]
+    pub fn cost(&self, node: &N) -> Option<C> {
+        self.costs.get(node).copied()
+    }
+
+    /// The cost of every node that was reached.
+    #[must_use]
+    pub fn costs(&self) -> &HashMap<N, C> {
+        &self.costs
+    }
+
+    /// The node before `node` on the cheapest path to it, `None` for starts and nodes not reached.
+    #[must_use]
+    pub fn predecessor(&self, node: &N) -> Option<&N> {
+        self.predecessors.get(node)
+    }
+
+    /// The node before each reached node that isn't a start.
+    #[must_use]
+    pub fn predecessors(&self) -> &HashMap<N, N> {
+        &self.predecessors
+    }
+
+    /// The cheapest path from a start to `node`, both included. `None` if it wasn't reached.
+    #[must_use]
+    pub fn path_to(&self, node: &N) -> Option<Vec<N>> {
+        if !self.costs.contains_key(node) {
+            return None;
+        }
+        let mut path = std::iter::successors(Some(node), |node| self.predecessors.get(*node))
+            .cloned()
+            .collect::<Vec<_>>();
+        path.reverse();
+        Some(path)
+    }
+
+    /// The cheapest path to the goal, see [`Paths::path_to`].
+    #[must_use]
+    pub fn goal_path(&self) -> Option<Vec<N>> {
+        self.path_to(self.goal.as_ref()?)
+    }
+
+    /// Record reaching `node` from `from` at `cost`, if that's cheaper than before.
+    fn relax(&mut self, node: N, from: &N, cost: C) -> bool
+    where
+        C: Ord,
+    {
+        match self.costs.entry(node.clone()) {
+            Entry::Occupied(entry) if *entry.get() <= cost => return false,
+            Entry::Occupied(mut entry) => {
+                entry.insert(cost);
+            }
+            Entry::Vacant(entry) => {
+                entry.insert(cost);
+            }
+        }
+        self.predecessors.insert(node, from.clone());
+        true
+    }
+}
+
+/// Breadth-first search, for graphs where every step costs 1. Costs are the number of steps.
+pub fn bfs<N, I>(
+    starts: impl IntoIterator<Item = N>,
+    mut neighbors: impl FnMut(&N) -> I,
+    mut is_goal: impl FnMut(&N) -> bool,
+) -> Paths<N, usize>
+where
+    N: Eq + Hash + Clone,
+    I: IntoIterator<Item = N>,
+{
+    let mut paths = Paths::new(starts, 0);
+    let mut queue = paths.costs.keys().cloned().collect::<VecDeque<_>>();
+
+    while let Some(node) = queue.pop_front() {
+        if is_goal(&node) {
+            paths.goal = Some(node);
+            break;
+        }
+        let cost = paths.costs[&node] + 1;
+        for neighbor in neighbors(&node) {
+            if !paths.costs.contains_key(&neighbor) {
+                paths.costs.insert(neighbor.clone(), cost);
+                paths.predecessors.insert(neighbor.clone(), node.clone());
+                queue.push_back(neighbor);
+            }
+        }
+    }
+
+    paths
+}
+
+/// Dijkstra's algorithm, for graphs with steps of different costs that are never negative.
+/// `neighbors` gives each neighbor with the cost of the step to it, and costs start from
+/// `C::default()`, which is 0 for numbers.
+pub fn dijkstra<N, C, I>(
+    starts: impl IntoIterator<Item = N>,
+    neighbors: impl FnMut(&N) -> I,
+    is_goal: impl FnMut(&N) -> bool,
+) -> Paths<N, C>
+where
+    N: Eq + Hash + Clone,
+    C: Copy + Ord + Add<Output = C> + Default,
+    I: IntoIterator<Item = (N, C)>,
+{
+    astar(starts, neighbors, |_| C::default(), is_goal)
+}
+
+/// A* search: Dijkstra's algorithm guided towards the goal by `heuristic`, an estimate of the
+/// cost from a node to the nearest goal. The path to the goal is the cheapest as long as the
+/// estimate never exceeds the real cost and never drops by more than the cost of a step, like the
+/// Manhattan distance on a grid without diagonal steps.
+pub fn astar<N, C, I>(
+    starts: impl IntoIterator<Item = N>,
+    mut neighbors: impl FnMut(&N) -> I,
+    mut heuristic: impl FnMut(&N) -> C,
+    mut is_goal: impl FnMut(&N) -> bool,
+) -> Paths<N, C>
+where
+    N: Eq + Hash + Clone,
+    C: Copy + Ord + Add<Output = C> + Default,
+    I: IntoIterator<Item = (N, C)>,
+{
+    let mut paths = Paths::new(starts, C::default());
+    let mut queue = paths
+        .costs
+        .keys()
+        .map(|node| Queued {
+            priority: heuristic(node),
+            cost: C::default(),
+            node: node.clone(),
+        })
+        .collect::<BinaryHeap<_>>();
+
+    while let Some(Queued { cost, node, .. }) = queue.pop() {
+        // A cheaper way to the node was found after this one was queued.
+        if paths.costs[&node] < cost {
+            continue;
+        }
+        if is_goal(&node) {
+            paths.goal = Some(node);
+            break;
+        }
+        for (neighbor, step) in neighbors(&node) {
+            let cost = cost + step;
+            if paths.relax(neighbor.clone(), &node, cost) {
+                queue.push(Queued {
+                    priority: cost + heuristic(&neighbor),
+                    cost,
+                    node: neighbor,
+                });
+            }
+        }
+    }
+
+    paths
+}
+
+/// A node waiting in the queue. Ordered so the lowest priority comes out of a max-heap first.
+struct Queued<N, C> {
+    priority: C,
+    cost: C,
+    node: N,
+}
+
+impl<N, C: Ord> PartialEq for Queued<N, C> {
+    fn eq(&self, other: &Self) -> bool {
+        self.priority == other.priority
+    }
+}
+
+impl<N, C: Ord> Eq for Queued<N, C> {}
+
+impl<N, C: Ord> PartialOrd for Queued<N, C> {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl<N, C: Ord> Ord for Queued<N, C> {
+    fn cmp(&self, other: &Self) -> Ordering {
+        other.priority.cmp(&self.priority)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{Grid, Input, Point};
+
+    const MAZE: &str = "\
+S.#.....
+.##.###.
+....#...
+.##...#E";
+
+    fn maze() -> (Grid<char>, Point, Point) {
+        let grid = Input::from_contents(MAZE).grid().unwrap();
+        let find = |target| {
+            grid.iter()
+                .find(|&(_, &c)| c == target)
+                .map(|(point, _)| point)
+                .unwrap()
+        };
+        let (start, end) = (find('S'), find('E'));
+        (grid, start, end)
+    }
+
+    fn open_neighbors(grid: &Grid<char>, point: Point) -> impl Iterator<Item = Point> + '_ {
+        grid.neighbors4(point)
+            .filter(|&(_, &c)| c != '#')
+            .map(|(point, _)| point)
+    }
+
+    #[test]
+    fn test_bfs_on_grid() {
+        let (grid, start, end) = maze();
+        let paths = bfs([start], |&point| open_neighbors(&grid, point), |_| false);
+        assert_eq!(paths.cost(&end), Some(12));
+        assert_eq!(paths.cost(&Point::new(2, 0)), None);
+
+        let path = paths.path_to(&end).unwrap();
+        assert_eq!(path.len(), 13);
+        assert_eq!((path[0], path[12]), (start, end));
+        assert!(path.windows(2).all(|step| step[0].manhattan(step[1]) == 1));
+        assert_eq!(paths.predecessor(&start), None);
+    }
+
+    #[test]
+    fn test_bfs_over_states() {
+        // The fewest doublings and increments from 1 to 10.
+        let paths = bfs([1_u32], |&n| [n + 1, n * 2], |&n| n == 10);
+        assert_eq!(paths.goal(), Some(&10));
+        assert_eq!(paths.cost(&10), Some(4));
+        assert_eq!(paths.goal_path(), Some(vec![1, 2, 4, 5, 10]));
+    }
+
+    #[test]
+    fn test_dijkstra() {
+        let edges = HashMap::from([
+            ('a', vec![('b', 7), ('c', 9), ('f', 14)]),
+            ('b', vec![('c', 10), ('d', 15)]),
+            ('c', vec![('d', 11), ('f', 2)]),
+            ('d', vec![('e', 6)]),
+            ('f', vec![('e', 9)]),
+        ]);
+        let neighbors = |node: &char| edges.get(node).cloned().unwrap_or_default();
+
+        let paths = dijkstra(['a'], neighbors, |_| false);
+        assert_eq!(paths.cost(&'e'), Some(20));
+        assert_eq!(paths.path_to(&'e'), Some(vec!['a', 'c', 'f', 'e']));
+        assert_eq!(paths.cost(&'d'), Some(20));
+
+        let paths = dijkstra(['a'], neighbors, |&node| node == 'c');
+        assert_eq!(paths.goal(), Some(&'c'));
+        assert_eq!(paths.cost(&'c'), Some(9));
+        assert_eq!(paths.cost(&'e'), None);
+    }
+
+    #[test]
+    fn test_astar() {
+        let (grid, start, end) = maze();
+        let neighbors = |&point: &Point| open_neighbors(&grid, point).map(|next| (next, 1_u64));
+
+        let paths = astar(
+            [start],
+            neighbors,
+            |point| point.manhattan(end),
+            |&p| p == end,
+        );
+        assert_eq!(paths.goal(), Some(&end));
+        assert_eq!(paths.cost(&end), Some(12));
+        assert_eq!(paths.goal_path().map(|path| path.len()), Some(13));
+
+        let explored = dijkstra([start], neighbors, |&p| p == end).costs().len();
+        assert!(paths.costs().len() <= explored);
+
+        let walled = astar([start], neighbors, |_| 0, |&p| p == Point::new(2, 0));
+        assert_eq!(walled.goal(), None);
+    }
+}
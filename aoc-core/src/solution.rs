@@ -0,0 +1,259 @@
+//! Solutions as library units, and a registry to look them up by year and day.
+
+use std::{any::Any, collections::BTreeMap, fmt, marker::PhantomData, str::FromStr};
+
+use crate::{
+    answer::Answer,
+    diagnostic::ParseError,
+    input::{Input, InputLoader},
+};
+
+/// The solution to one day of puzzles, split into parsing the input and solving each part.
+pub trait Solution {
+    /// The input parsed into whatever both parts work on.
+    type Parsed: 'static;
+
+    /// # Errors
+    /// Returns an error pointing at what's wrong with the input if it's malformed.
+    fn parse(input: &Input) -> Result<Self::Parsed, ParseError>;
+
+    /// # Errors
+    /// Returns an error if the input is well-formed but has no answer to this part, such as too
+    /// few entries for it.
+    fn part_one(parsed: &Self::Parsed) -> Result<Answer, ParseError>;
+
+    /// # Errors
+    /// Returns an error if the input is well-formed but has no answer to this part.
+    fn part_two(parsed: &Self::Parsed) -> Result<Answer, ParseError>;
+}
+
+/// One of the two parts of a puzzle.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum Part {
+    One,
+    Two,
+}
+
+impl Part {
+    /// Both parts, in order.
+    pub const ALL: [Part; 2] = [Part::One, Part::Two];
+}
+
+impl fmt::Display for Part {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::One => write!(f, "1"),
+            Self::Two => write!(f, "2"),
+        }
+    }
+}
+
+#[derive(Debug, PartialEq, Eq)]
+pub struct ParsePartError;
+
+impl fmt::Display for ParsePartError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "expected part 1 or 2")
+    }
+}
+
+impl FromStr for Part {
+    type Err = ParsePartError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "1" => Ok(Self::One),
+            "2" => Ok(Self::Two),
+            _ => Err(ParsePartError),
+        }
+    }
+}
+
+/// An input parsed by a registered day, ready for either part.
+pub struct Parsed(Box<dyn Any>);
+
+/// A [`Solution`] with its types erased, so days can live side by side in a registry.
+trait DynSolution {
+    fn parse(&self, input: &Input) -> Result<Parsed, ParseError>;
+    fn part_one(&self, parsed: &Parsed) -> Result<Answer, ParseError>;
+    fn part_two(&self, parsed: &Parsed) -> Result<Answer, ParseError>;
+}
+
+struct Erased<S>(PhantomData<fn() -> S>);
+
+impl<S: Solution> Erased<S> {
+    fn downcast(parsed: &Parsed) -> &S::Parsed {
+        parsed
+            .0
+            .downcast_ref()
+            .expect("expect parsed input to come from the same day")
+    }
+}
+
+impl<S: Solution> DynSolution for Erased<S> {
+    fn parse(&self, input: &Input) -> Result<Parsed, ParseError> {
+        S::parse(input).map(|parsed| Parsed(Box::new(parsed)))
+    }
+
+    fn part_one(&self, parsed: &Parsed) -> Result<Answer, ParseError> {
+        S::part_one(Self::downcast(parsed))
+    }
+
+    fn part_two(&self, parsed: &Parsed) -> Result<Answer, ParseError> {
+        S::part_two(Self::downcast(parsed))
+    }
+}
+
+/// A solution registered for a year and day.
+pub struct Day {
+    pub year: u16,
+    pub day: u8,
+    inputs: InputLoader,
+    solution: Box<dyn DynSolution>,
+}
+
+impl Day {
+    /// Loads the inputs of this day's year.
+    #[must_use]
+    pub fn inputs(&self) -> &InputLoader {
+        &self.inputs
+    }
+
+    /// # Errors
+    /// Returns an error pointing at what's wrong with the input if it's malformed.
+    pub fn parse(&self, input: &Input) -> Result<Parsed, ParseError> {
+        self.solution.parse(input)
+    }
+
+    /// # Errors
+    /// Returns an error if the input has no answer to part one.
+    /// # Panics
+    /// Panics if `parsed` was parsed by another day.
+    pub fn part_one(&self, parsed: &Parsed) -> Result<Answer, ParseError> {
+        self.solution.part_one(parsed)
+    }
+
+    /// # Errors
+    /// Returns an error if the input has no answer to part two.
+    /// # Panics
+    /// Panics if `parsed` was parsed by another day.
+    pub fn part_two(&self, parsed: &Parsed) -> Result<Answer, ParseError> {
+        self.solution.part_two(parsed)
+    }
+
+    /// Solve either part.
+    /// # Errors
+    /// Returns an error if the input has no answer to `part`.
+    /// # Panics
+    /// Panics if `parsed` was parsed by another day.
+    pub fn solve(&self, parsed: &Parsed, part: Part) -> Result<Answer, ParseError> {
+        match part {
+            Part::One => self.part_one(parsed),
+            Part::Two => self.part_two(parsed),
+        }
+    }
+}
+
+/// Every registered solution, ordered by year and day.
+#[derive(Default)]
+pub struct Registry {
+    days: BTreeMap<(u16, u8), Day>,
+}
+
+impl Registry {
+    #[must_use]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Register `S` as the solution for `day` of the year `inputs` loads.
+    /// # Panics
+    /// Panics if the day already has a solution.
+    pub fn register<S: Solution + 'static>(&mut self, inputs: &InputLoader, day: u8) {
+        let year = inputs.year();
+        let previous = self.days.insert(
+            (year, day),
+            Day {
+                year,
+                day,
+                inputs: inputs.clone(),
+                solution: Box::new(Erased::<S>(PhantomData)),
+            },
+        );
+        assert!(
+            previous.is_none(),
+            "expect {year} day {day} to be registered once"
+        );
+    }
+
+    #[must_use]
+    pub fn get(&self, year: u16, day: u8) -> Option<&Day> {
+        self.days.get(&(year, day))
+    }
+
+    /// All registered days, ordered by year and day.
+    pub fn days(&self) -> impl Iterator<Item = &Day> {
+        self.days.values()
+    }
+}
+
+#[cfg(test)]
+pub(crate) mod tests {
+    use super::*;
+
+    /// A solution for tests: part one sums the lines, part two is unsolved.
+    pub(crate) struct Sum;
+
+    impl Solution for Sum {
+        type Parsed = Vec<u32>;
+
+        fn parse(input: &Input) -> Result<Self::Parsed, ParseError> {
+            Ok(input.parse_lines()?)
+        }
+
+        fn part_one(parsed: &Self::Parsed) -> Result<Answer, ParseError> {
+            Ok(parsed.iter().sum::<u32>().into())
+        }
+
+        fn part_two(_parsed: &Self::Parsed) -> Result<Answer, ParseError> {
+            Ok(Answer::Unsolved)
+        }
+    }
+
+    const INPUTS: InputLoader = InputLoader::new(2000, env!("CARGO_MANIFEST_DIR"));
+
+    #[test]
+    fn test_registry() {
+        let mut registry = Registry::new();
+        registry.register::<Sum>(&INPUTS, 2);
+        registry.register::<Sum>(&INPUTS, 1);
+
+        let days = registry
+            .days()
+            .map(|day| (day.year, day.day))
+            .collect::<Vec<_>>();
+        assert_eq!(days, [(2000, 1), (2000, 2)]);
+
+        let day = registry.get(2000, 1).unwrap();
+        let parsed = day.parse(&Input::from_contents("1\n2\n")).unwrap();
+        assert_eq!(day.part_one(&parsed), Ok(Answer::from(3)));
+        assert_eq!(day.part_two(&parsed), Ok(Answer::Unsolved));
+
+        let input = Input::from_contents("1\nx\n");
+        let err = day.parse(&input).err().unwrap();
+        assert_eq!(
+            err.to_string(),
+            "line 2, column 1: invalid digit found in string"
+        );
+
+        assert!(registry.get(2000, 3).is_none());
+    }
+
+    #[test]
+    #[should_panic(expected = "expect 2000 day 1 to be registered once")]
+    fn test_register_twice() {
+        let mut registry = Registry::new();
+        registry.register::<Sum>(&INPUTS, 1);
+        registry.register::<Sum>(&INPUTS, 1);
+    }
+}
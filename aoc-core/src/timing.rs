@@ -0,0 +1,155 @@
+//! Timing solutions over repeated runs.
+
+use std::{
+    fmt,
+    hint::black_box,
+    iter::Sum,
+    ops::Add,
+    time::{Duration, Instant},
+};
+
+/// The spread of how long repeated runs took.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct Timings {
+    pub min: Duration,
+    pub median: Duration,
+    pub max: Duration,
+}
+
+impl Timings {
+    /// Summarize the durations of runs.
+    /// # Panics
+    /// Panics if there are no samples.
+    #[must_use]
+    pub fn from_samples(samples: &mut [Duration]) -> Self {
+        assert!(!samples.is_empty(), "expect at least one sample");
+        samples.sort_unstable();
+
+        let middle = samples.len() / 2;
+        let median = if samples.len().is_multiple_of(2) {
+            (samples[middle - 1] + samples[middle]) / 2
+        } else {
+            samples[middle]
+        };
+
+        Self {
+            min: samples[0],
+            median,
+            max: samples[samples.len() - 1],
+        }
+    }
+}
+
+/// Adds up each statistic on its own, for totals across days.
+impl Add for Timings {
+    type Output = Self;
+
+    fn add(self, rhs: Self) -> Self {
+        Self {
+            min: self.min + rhs.min,
+            median: self.median + rhs.median,
+            max: self.max + rhs.max,
+        }
+    }
+}
+
+impl Sum for Timings {
+    fn sum<I: Iterator<Item = Self>>(iter: I) -> Self {
+        iter.fold(Self::default(), Add::add)
+    }
+}
+
+/// Run `f` `runs` times, at least once, and return what the last run returned with how long each
+/// run took.
+pub fn sample_runs<T>(runs: usize, mut f: impl FnMut() -> T) -> (T, Vec<Duration>) {
+    let mut samples = Vec::with_capacity(runs.max(1));
+    let mut run = || {
+        let start = Instant::now();
+        let result = black_box(f());
+        samples.push(start.elapsed());
+        result
+    };
+
+    let mut result = run();
+    for _ in 1..runs {
+        result = run();
+    }
+
+    (result, samples)
+}
+
+/// Run `f` `runs` times, at least once, and return what the last run returned with the timings.
+pub fn time_runs<T>(runs: usize, f: impl FnMut() -> T) -> (T, Timings) {
+    let (result, mut samples) = sample_runs(runs, f);
+    (result, Timings::from_samples(&mut samples))
+}
+
+/// Displays a duration in the unit that suits it, e.g. `1.25 ms`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Elapsed(pub Duration);
+
+impl fmt::Display for Elapsed {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let nanos = self.0.as_nanos();
+        let secs = self.0.as_secs_f64();
+        if nanos < 1_000 {
+            write!(f, "{nanos} ns")
+        } else if nanos < 1_000_000 {
+            write!(f, "{:.2} µs", secs * 1e6)
+        } else if nanos < 1_000_000_000 {
+            write!(f, "{:.2} ms", secs * 1e3)
+        } else {
+            write!(f, "{secs:.2} s")
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_from_samples() {
+        let ms = Duration::from_millis;
+
+        let timings = Timings::from_samples(&mut [ms(5), ms(1), ms(3)]);
+        assert_eq!(
+            timings,
+            Timings {
+                min: ms(1),
+                median: ms(3),
+                max: ms(5)
+            }
+        );
+
+        let timings = Timings::from_samples(&mut [ms(4), ms(1), ms(2), ms(9)]);
+        assert_eq!(timings.median, ms(3));
+
+        let total = [timings, timings].into_iter().sum::<Timings>();
+        assert_eq!(total.max, ms(18));
+    }
+
+    #[test]
+    fn test_time_runs() {
+        let mut calls = 0;
+        let (result, _) = time_runs(3, || {
+            calls += 1;
+            calls
+        });
+        assert_eq!(result, 3);
+
+        let (result, _) = time_runs(0, || "once");
+        assert_eq!(result, "once");
+    }
+
+    #[test]
+    fn test_elapsed() {
+        assert_eq!(Elapsed(Duration::from_nanos(870)).to_string(), "870 ns");
+        assert_eq!(
+            Elapsed(Duration::from_nanos(12_346)).to_string(),
+            "12.35 µs"
+        );
+        assert_eq!(Elapsed(Duration::from_micros(1_250)).to_string(), "1.25 ms");
+        assert_eq!(Elapsed(Duration::from_millis(2_500)).to_string(), "2.50 s");
+    }
+}
@@ -0,0 +1,38 @@
+//! Counting allocations needs `CountingAllocator` installed as the global allocator, which only a
+//! binary of its own can do.
+
+use std::hint::black_box;
+
+use aoc_core::{measure, CountingAllocator};
+
+#[global_allocator]
+static ALLOCATOR: CountingAllocator = CountingAllocator;
+
+// The counters are shared by every thread, so everything is measured from a single test.
+#[test]
+fn test_measure() {
+    let (vec, allocations) = measure(|| black_box(Vec::<u8>::with_capacity(1000)));
+    assert_eq!(vec.capacity(), 1000);
+    assert!(allocations.count >= 1, "{allocations:?}");
+    assert!(allocations.bytes >= 1000, "{allocations:?}");
+    assert!(allocations.peak >= 1000, "{allocations:?}");
+
+    // Freed memory still counts towards the bytes, but not the peak.
+    let (_, allocations) = measure(|| {
+        drop(black_box(vec![0_u8; 4000]));
+        drop(black_box(vec![0_u8; 4000]));
+    });
+    assert!(allocations.count >= 2, "{allocations:?}");
+    assert!(allocations.bytes >= 8000, "{allocations:?}");
+    assert!((4000..8000).contains(&allocations.peak), "{allocations:?}");
+
+    // Growing a vec counts its reallocations, and only the bytes it grows by.
+    let (_, allocations) = measure(|| {
+        let mut vec = black_box(Vec::<u8>::with_capacity(1000));
+        // Room for 3000 bytes in all, 2000 more than before.
+        vec.reserve_exact(3000);
+        vec
+    });
+    assert!(allocations.count >= 2, "{allocations:?}");
+    assert!((3000..4000).contains(&allocations.bytes), "{allocations:?}");
+}
@@ -0,0 +1,604 @@
+//! Command line arguments of the runner.
+
+use std::{fmt, path::PathBuf, str::FromStr};
+
+use aoc_core::Part;
+
+pub const USAGE: &str = "\
+usage: aoc <year> <day> [--part 1|2] [--input <path|->]
+       aoc verify [<year> [<day>]] [--record]
+       aoc time [<year> [<day>]] [--runs <n>]
+       aoc bench [<year> [<day>]] [--runs <n>] [--save] [--baseline <path>]
+       aoc new <year> <day>
+
+Solve days:
+  <year>            the year to run, e.g. 2023
+  <day>             a day, an inclusive range of days like 1-5, or `all`
+  --part 1|2        only run one part
+  --input <path|->  read the input from a file, or from stdin with `-`, instead of the
+                    day's input file. Only works for a single day.
+
+Check days against the confirmed answers in each year's answers.txt, every day by default:
+  --record          confirm the answers of parts that have none yet, after submitting them
+
+Time parsing and each part of days on their real input, every day by default:
+  --runs <n>        how many times to run each step, 10 by default
+
+Benchmark days on their real input and compare them to a saved baseline, every day by default:
+  --runs <n>        how many times to run each step, 30 by default
+  --save            save the results as the baseline of the days that ran
+  --baseline <path> the baseline file instead of target/aoc-bench/baseline.txt
+
+Start a new day: create its module with tests, register it, and create an empty input and example.
+Never overwrites a day that already has a module.";
+
+/// Everything that can be wrong with the arguments, or a request for help.
+#[derive(Debug, PartialEq, Eq)]
+pub enum ArgsError {
+    /// Help was asked for, this isn't really an error.
+    Help,
+    Missing(&'static str),
+    Invalid {
+        what: &'static str,
+        value: String,
+    },
+    Unexpected(String),
+    /// `--input` was given while running more than one day.
+    InputNeedsOneDay,
+}
+
+impl fmt::Display for ArgsError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Help => write!(f, "{USAGE}"),
+            Self::Missing(what) => write!(f, "missing {what}"),
+            Self::Invalid { what, value } => write!(f, "invalid {what} `{value}`"),
+            Self::Unexpected(arg) => write!(f, "unexpected argument `{arg}`"),
+            Self::InputNeedsOneDay => write!(f, "--input only works for a single day"),
+        }
+    }
+}
+
+/// The days of a year to run.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Days {
+    One(u8),
+    /// An inclusive range of days.
+    Range(u8, u8),
+    All,
+}
+
+impl Days {
+    pub fn contains(self, day: u8) -> bool {
+        match self {
+            Self::One(one) => day == one,
+            Self::Range(from, to) => (from..=to).contains(&day),
+            Self::All => true,
+        }
+    }
+}
+
+impl fmt::Display for Days {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::One(day) => write!(f, "day {day}"),
+            Self::Range(from, to) => write!(f, "days {from}-{to}"),
+            Self::All => write!(f, "any day"),
+        }
+    }
+}
+
+impl FromStr for Days {
+    type Err = ArgsError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let invalid = || ArgsError::Invalid {
+            what: "day",
+            value: s.to_string(),
+        };
+        let parse_day = |day: &str| match day.parse() {
+            Ok(day @ 1..=25) => Ok(day),
+            _ => Err(invalid()),
+        };
+
+        if s == "all" {
+            return Ok(Self::All);
+        }
+
+        match s.split_once('-') {
+            Some((from, to)) => {
+                let (from, to) = (parse_day(from)?, parse_day(to)?);
+                if from > to {
+                    return Err(invalid());
+                }
+                Ok(Self::Range(from, to))
+            }
+            None => parse_day(s).map(Self::One),
+        }
+    }
+}
+
+/// Where to read the input from, when not from the day's input file.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum InputSource {
+    Stdin,
+    Path(PathBuf),
+}
+
+impl From<String> for InputSource {
+    fn from(arg: String) -> Self {
+        if arg == "-" {
+            Self::Stdin
+        } else {
+            Self::Path(arg.into())
+        }
+    }
+}
+
+/// What the runner was asked to do.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Command {
+    Run(Run),
+    Verify(Verify),
+    Time(Time),
+    Bench(Bench),
+    New(New),
+}
+
+impl Command {
+    /// Parse the arguments, without the name of the binary.
+    pub fn parse(args: impl IntoIterator<Item = String>) -> Result<Self, ArgsError> {
+        let mut args = args.into_iter().peekable();
+        match args.peek().map(String::as_str) {
+            Some("verify") => {
+                args.next();
+                Verify::parse(args).map(Self::Verify)
+            }
+            Some("time") => {
+                args.next();
+                Time::parse(args).map(Self::Time)
+            }
+            Some("bench") => {
+                args.next();
+                Bench::parse(args).map(Self::Bench)
+            }
+            Some("new") => {
+                args.next();
+                New::parse(args).map(Self::New)
+            }
+            _ => Run::parse(args).map(Self::Run),
+        }
+    }
+}
+
+fn parse_year(year: String) -> Result<u16, ArgsError> {
+    year.parse().map_err(|_| ArgsError::Invalid {
+        what: "year",
+        value: year,
+    })
+}
+
+/// The optional `[<year> [<day>]]` of commands that default to every day of every year.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Selection {
+    /// Only this year, or every year when `None`.
+    pub year: Option<u16>,
+    pub days: Days,
+}
+
+impl Selection {
+    fn parse(positional: Vec<String>) -> Result<Self, ArgsError> {
+        let mut positional = positional.into_iter();
+        let year = positional.next().map(parse_year).transpose()?;
+        let days = match positional.next() {
+            Some(days) => days.parse()?,
+            None => Days::All,
+        };
+        match positional.next() {
+            Some(arg) => Err(ArgsError::Unexpected(arg)),
+            None => Ok(Self { year, days }),
+        }
+    }
+
+    pub fn includes(&self, year: u16, day: u8) -> bool {
+        self.year.is_none_or(|only| only == year) && self.days.contains(day)
+    }
+}
+
+fn parse_runs(value: String) -> Result<usize, ArgsError> {
+    match value.parse() {
+        Ok(runs @ 1..) => Ok(runs),
+        _ => Err(ArgsError::Invalid {
+            what: "runs",
+            value,
+        }),
+    }
+}
+
+fn parse_part(part: String) -> Result<Part, ArgsError> {
+    part.parse().map_err(|_| ArgsError::Invalid {
+        what: "part",
+        value: part,
+    })
+}
+
+/// Solve days and print their answers.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Run {
+    pub year: u16,
+    pub days: Days,
+    /// Only this part, or both when `None`.
+    pub part: Option<Part>,
+    pub input: Option<InputSource>,
+}
+
+impl Run {
+    fn parse(args: impl IntoIterator<Item = String>) -> Result<Self, ArgsError> {
+        let mut args = args.into_iter();
+        let mut positional = Vec::new();
+        let mut part = None;
+        let mut input = None;
+
+        while let Some(arg) = args.next() {
+            match arg.as_str() {
+                "-h" | "--help" => return Err(ArgsError::Help),
+                "--part" => {
+                    part = Some(parse_part(args.next().ok_or(ArgsError::Missing("part"))?)?)
+                }
+                "--input" => input = Some(args.next().ok_or(ArgsError::Missing("input"))?.into()),
+                _ if arg.starts_with('-') => return Err(ArgsError::Unexpected(arg)),
+                _ => positional.push(arg),
+            }
+        }
+
+        let mut positional = positional.into_iter();
+        let year = parse_year(positional.next().ok_or(ArgsError::Missing("year"))?)?;
+        let days: Days = positional
+            .next()
+            .ok_or(ArgsError::Missing("day"))?
+            .parse()?;
+        if let Some(arg) = positional.next() {
+            return Err(ArgsError::Unexpected(arg));
+        }
+
+        if input.is_some() && !matches!(days, Days::One(_)) {
+            return Err(ArgsError::InputNeedsOneDay);
+        }
+
+        Ok(Self {
+            year,
+            days,
+            part,
+            input,
+        })
+    }
+
+    /// The parts to run, in order.
+    pub fn parts(&self) -> &'static [Part] {
+        match self.part {
+            Some(Part::One) => &[Part::One],
+            Some(Part::Two) => &[Part::Two],
+            None => &Part::ALL,
+        }
+    }
+}
+
+/// Check days against their confirmed answers.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Verify {
+    pub selection: Selection,
+    pub record: bool,
+}
+
+impl Verify {
+    fn parse(args: impl IntoIterator<Item = String>) -> Result<Self, ArgsError> {
+        let mut positional = Vec::new();
+        let mut record = false;
+
+        for arg in args {
+            match arg.as_str() {
+                "-h" | "--help" => return Err(ArgsError::Help),
+                "--record" => record = true,
+                _ if arg.starts_with('-') => return Err(ArgsError::Unexpected(arg)),
+                _ => positional.push(arg),
+            }
+        }
+
+        let selection = Selection::parse(positional)?;
+        Ok(Self { selection, record })
+    }
+}
+
+/// Time days on their real input.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Time {
+    pub selection: Selection,
+    /// How many times to run each step.
+    pub runs: usize,
+}
+
+impl Time {
+    const DEFAULT_RUNS: usize = 10;
+
+    fn parse(args: impl IntoIterator<Item = String>) -> Result<Self, ArgsError> {
+        let mut args = args.into_iter();
+        let mut positional = Vec::new();
+        let mut runs = Self::DEFAULT_RUNS;
+
+        while let Some(arg) = args.next() {
+            match arg.as_str() {
+                "-h" | "--help" => return Err(ArgsError::Help),
+                "--runs" => runs = parse_runs(args.next().ok_or(ArgsError::Missing("runs"))?)?,
+                _ if arg.starts_with('-') => return Err(ArgsError::Unexpected(arg)),
+                _ => positional.push(arg),
+            }
+        }
+
+        let selection = Selection::parse(positional)?;
+        Ok(Self { selection, runs })
+    }
+}
+
+/// Benchmark days and compare them to a baseline.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Bench {
+    pub selection: Selection,
+    /// How many times to run each step.
+    pub runs: usize,
+    pub save: bool,
+    /// The baseline file, or the default one when `None`.
+    pub baseline: Option<PathBuf>,
+}
+
+impl Bench {
+    const DEFAULT_RUNS: usize = 30;
+
+    fn parse(args: impl IntoIterator<Item = String>) -> Result<Self, ArgsError> {
+        let mut args = args.into_iter();
+        let mut positional = Vec::new();
+        let mut runs = Self::DEFAULT_RUNS;
+        let mut save = false;
+        let mut baseline = None;
+
+        while let Some(arg) = args.next() {
+            match arg.as_str() {
+                "-h" | "--help" => return Err(ArgsError::Help),
+                "--runs" => runs = parse_runs(args.next().ok_or(ArgsError::Missing("runs"))?)?,
+                "--save" => save = true,
+                "--baseline" => {
+                    baseline = Some(args.next().ok_or(ArgsError::Missing("baseline"))?.into());
+                }
+                _ if arg.starts_with('-') => return Err(ArgsError::Unexpected(arg)),
+                _ => positional.push(arg),
+            }
+        }
+
+        let selection = Selection::parse(positional)?;
+        Ok(Self {
+            selection,
+            runs,
+            save,
+            baseline,
+        })
+    }
+}
+
+/// Start a new day.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct New {
+    pub year: u16,
+    pub day: u8,
+}
+
+impl New {
+    fn parse(args: impl IntoIterator<Item = String>) -> Result<Self, ArgsError> {
+        let mut positional = Vec::new();
+        for arg in args {
+            match arg.as_str() {
+                "-h" | "--help" => return Err(ArgsError::Help),
+                _ if arg.starts_with('-') => return Err(ArgsError::Unexpected(arg)),
+                _ => positional.push(arg),
+            }
+        }
+
+        let mut positional = positional.into_iter();
+        let year = parse_year(positional.next().ok_or(ArgsError::Missing("year"))?)?;
+        let day = positional.next().ok_or(ArgsError::Missing("day"))?;
+        let Ok(Days::One(day)) = day.parse() else {
+            return Err(ArgsError::Invalid {
+                what: "day",
+                value: day,
+            });
+        };
+        if let Some(arg) = positional.next() {
+            return Err(ArgsError::Unexpected(arg));
+        }
+
+        Ok(Self { year, day })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn parse(args: &str) -> Result<Command, ArgsError> {
+        Command::parse(args.split_whitespace().map(str::to_string))
+    }
+
+    fn run(args: &str) -> Result<Run, ArgsError> {
+        match parse(args)? {
+            Command::Run(run) => Ok(run),
+            command => panic!("expected a run, got {command:?}"),
+        }
+    }
+
+    #[test]
+    fn test_parse() {
+        assert_eq!(
+            parse("2022 5"),
+            Ok(Command::Run(Run {
+                year: 2022,
+                days: Days::One(5),
+                part: None,
+                input: None,
+            }))
+        );
+        assert_eq!(
+            run("--part 2 2023 1-3 ").map(|args| (args.days, args.parts())),
+            Ok((Days::Range(1, 3), [Part::Two].as_slice()))
+        );
+        assert_eq!(run("2023 all").map(|args| args.days), Ok(Days::All));
+        assert_eq!(
+            run("2023 3 --input -").map(|args| args.input),
+            Ok(Some(InputSource::Stdin))
+        );
+        assert_eq!(
+            run("2023 3 --input other.txt").map(|args| args.input),
+            Ok(Some(InputSource::Path("other.txt".into())))
+        );
+    }
+
+    #[test]
+    fn test_parse_verify() {
+        assert_eq!(
+            parse("verify"),
+            Ok(Command::Verify(Verify {
+                selection: Selection {
+                    year: None,
+                    days: Days::All,
+                },
+                record: false,
+            }))
+        );
+        assert_eq!(
+            parse("verify 2022 1-3 --record"),
+            Ok(Command::Verify(Verify {
+                selection: Selection {
+                    year: Some(2022),
+                    days: Days::Range(1, 3),
+                },
+                record: true,
+            }))
+        );
+        assert_eq!(
+            parse("verify 2022 --part 1"),
+            Err(ArgsError::Unexpected("--part".to_string()))
+        );
+    }
+
+    #[test]
+    fn test_parse_time() {
+        assert_eq!(
+            parse("time"),
+            Ok(Command::Time(Time {
+                selection: Selection {
+                    year: None,
+                    days: Days::All,
+                },
+                runs: 10,
+            }))
+        );
+        assert_eq!(
+            parse("time 2022 8 --runs 3"),
+            Ok(Command::Time(Time {
+                selection: Selection {
+                    year: Some(2022),
+                    days: Days::One(8),
+                },
+                runs: 3,
+            }))
+        );
+        assert_eq!(
+            parse("time --runs 0"),
+            Err(ArgsError::Invalid {
+                what: "runs",
+                value: "0".to_string()
+            })
+        );
+    }
+
+    #[test]
+    fn test_parse_bench() {
+        assert_eq!(
+            parse("bench 2023 --save"),
+            Ok(Command::Bench(Bench {
+                selection: Selection {
+                    year: Some(2023),
+                    days: Days::All,
+                },
+                runs: 30,
+                save: true,
+                baseline: None,
+            }))
+        );
+        assert_eq!(
+            parse("bench --runs 5 --baseline before.txt").map(|command| match command {
+                Command::Bench(bench) => (bench.runs, bench.baseline),
+                command => panic!("expected a bench, got {command:?}"),
+            }),
+            Ok((5, Some("before.txt".into())))
+        );
+    }
+
+    #[test]
+    fn test_parse_new() {
+        assert_eq!(
+            parse("new 2023 4"),
+            Ok(Command::New(New { year: 2023, day: 4 }))
+        );
+        assert_eq!(parse("new 2023"), Err(ArgsError::Missing("day")));
+        assert_eq!(
+            parse("new 2023 all"),
+            Err(ArgsError::Invalid {
+                what: "day",
+                value: "all".to_string()
+            })
+        );
+    }
+
+    #[test]
+    fn test_parse_errors() {
+        assert_eq!(parse("--help 2023"), Err(ArgsError::Help));
+        assert_eq!(parse("2023"), Err(ArgsError::Missing("day")));
+        assert_eq!(parse("2023 1 --part"), Err(ArgsError::Missing("part")));
+        assert_eq!(
+            parse("2023 26"),
+            Err(ArgsError::Invalid {
+                what: "day",
+                value: "26".to_string()
+            })
+        );
+        assert_eq!(
+            parse("2023 5-3"),
+            Err(ArgsError::Invalid {
+                what: "day",
+                value: "5-3".to_string()
+            })
+        );
+        assert_eq!(
+            parse("2023 1 --part 3"),
+            Err(ArgsError::Invalid {
+                what: "part",
+                value: "3".to_string()
+            })
+        );
+        assert_eq!(
+            parse("2023 1 2"),
+            Err(ArgsError::Unexpected("2".to_string()))
+        );
+        assert_eq!(
+            parse("2023 all --input -"),
+            Err(ArgsError::InputNeedsOneDay)
+        );
+    }
+
+    #[test]
+    fn test_days_contains() {
+        assert!(Days::One(3).contains(3));
+        assert!(!Days::One(3).contains(4));
+        assert!(Days::Range(2, 4).contains(4));
+        assert!(!Days::Range(2, 4).contains(5));
+        assert!(Days::All.contains(25));
+    }
+}
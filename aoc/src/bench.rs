@@ -0,0 +1,133 @@
+//! Benchmarking days and comparing them to a baseline saved on disk.
+
+use std::{
+    path::{Path, PathBuf},
+    process::ExitCode,
+};
+
+use aoc_core::{sample_runs, Baseline, Change, Elapsed, Part, Registry, Sample, Step};
+
+use crate::{args::Bench, table::Table};
+
+/// Where baselines go unless told otherwise. Timings only mean something on the machine that took
+/// them, so they live in the untracked target directory of the workspace.
+fn default_baseline() -> PathBuf {
+    Path::new(env!("CARGO_MANIFEST_DIR"))
+        .parent()
+        .expect("the runner lives in the workspace")
+        .join("target/aoc-bench/baseline.txt")
+}
+
+pub fn bench(registry: &Registry, args: &Bench) -> ExitCode {
+    let days = registry
+        .days()
+        .filter(|day| args.selection.includes(day.year, day.day))
+        .collect::<Vec<_>>();
+    if days.is_empty() {
+        eprintln!("error: no solution for {}", args.selection.days);
+        return ExitCode::FAILURE;
+    }
+
+    let path = args.baseline.clone().unwrap_or_else(default_baseline);
+    let mut baseline = match Baseline::load(&path) {
+        Ok(baseline) => baseline,
+        Err(err) => {
+            eprintln!("error: {err}");
+            return ExitCode::FAILURE;
+        }
+    };
+
+    let mut table = Table::new(&[
+        "year", "day", "step", "mean", "std dev", "baseline", "change",
+    ]);
+    let mut samples = Vec::new();
+    let mut failed = false;
+    for day in days {
+        let input = match day.inputs().load(day.day) {
+            Ok(input) => input,
+            Err(err) => {
+                eprintln!("error: {err}");
+                failed = true;
+                continue;
+            }
+        };
+
+        // The first run of each step only warms up caches and the allocator and isn't counted.
+        let (parsed, durations) = sample_runs(args.runs + 1, || day.parse(&input));
+        let parsed = match parsed {
+            Ok(parsed) => parsed,
+            Err(err) => {
+                eprintln!(
+                    "error: {} day {}: {}",
+                    day.year,
+                    day.day,
+                    err.diagnostic(&input)
+                );
+                failed = true;
+                continue;
+            }
+        };
+        samples.push((day, Step::Parse, Sample::from_durations(&durations[1..])));
+        for part in Part::ALL {
+            let (answer, durations) = sample_runs(args.runs + 1, || day.solve(&parsed, part));
+            if let Err(err) = answer {
+                eprintln!(
+                    "error: {} day {} part {part}: {}",
+                    day.year,
+                    day.day,
+                    err.diagnostic(&input)
+                );
+                failed = true;
+                continue;
+            }
+            samples.push((
+                day,
+                Step::Part(part),
+                Sample::from_durations(&durations[1..]),
+            ));
+        }
+    }
+
+    for &(day, step, sample) in &samples {
+        let (previous, change) = match baseline.get(day.year, day.day, step) {
+            Some(previous) => {
+                let change = sample.compare(previous);
+                failed |= matches!(change, Change::Slower(_));
+                (Elapsed(previous.mean).to_string(), change.to_string())
+            }
+            None => ("-".to_string(), "new".to_string()),
+        };
+        table.push(vec![
+            day.year.to_string(),
+            day.day.to_string(),
+            step.to_string(),
+            Elapsed(sample.mean).to_string(),
+            Elapsed(sample.std_dev).to_string(),
+            previous,
+            change,
+        ]);
+    }
+
+    if !table.is_empty() {
+        print!("{table}");
+    }
+
+    if args.save && !samples.is_empty() {
+        for &(day, step, sample) in &samples {
+            baseline.insert(day.year, day.day, step, sample);
+        }
+        match baseline.save(&path) {
+            Ok(()) => eprintln!("saved {} samples to {}", samples.len(), path.display()),
+            Err(err) => {
+                eprintln!("error: failed to write {}: {err}", path.display());
+                failed = true;
+            }
+        }
+    }
+
+    if failed {
+        ExitCode::FAILURE
+    } else {
+        ExitCode::SUCCESS
+    }
+}
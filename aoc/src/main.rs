@@ -0,0 +1,59 @@
+//! Runs the solutions of every year from one place.
+//!
+//! `cargo aoc 2022 5` solves both parts of a day, `cargo aoc 2023 all --part 1` the first part
+//! of every day of a year, and `cargo aoc verify` checks every day against its confirmed answers.
+//! `cargo aoc time` reports how long each step of every day takes, and `cargo aoc bench` compares
+//! that to a saved baseline. `cargo aoc new 2023 4` starts a new day. Run `cargo aoc --help` for
+//! everything else.
+
+mod args;
+mod bench;
+mod run;
+mod scaffold;
+mod table;
+mod time;
+mod verify;
+
+use std::{env, process::ExitCode};
+
+use aoc_core::{InputLoader, Registry};
+
+use args::{ArgsError, Command, USAGE};
+
+/// Counts what every solution allocates, for `aoc time` to report.
+#[cfg(feature = "count-allocations")]
+#[global_allocator]
+static ALLOCATOR: aoc_core::CountingAllocator = aoc_core::CountingAllocator;
+
+/// Where the crate of every year lives.
+const YEARS: [&InputLoader; 2] = [&advent_of_code_2022::INPUTS, &advent_of_code_2023::INPUTS];
+
+fn registry() -> Registry {
+    let mut registry = Registry::new();
+    advent_of_code_2022::register(&mut registry);
+    advent_of_code_2023::register(&mut registry);
+    registry
+}
+
+fn main() -> ExitCode {
+    let command = match Command::parse(env::args().skip(1)) {
+        Ok(command) => command,
+        Err(ArgsError::Help) => {
+            println!("{USAGE}");
+            return ExitCode::SUCCESS;
+        }
+        Err(err) => {
+            eprintln!("error: {err}\n\n{USAGE}");
+            return ExitCode::FAILURE;
+        }
+    };
+
+    let registry = registry();
+    match command {
+        Command::Run(args) => run::run(&registry, &args),
+        Command::Verify(args) => verify::verify(&registry, &args),
+        Command::Time(args) => time::time(&registry, &args),
+        Command::Bench(args) => bench::bench(&registry, &args),
+        Command::New(args) => scaffold::new(&YEARS, &args),
+    }
+}
@@ -0,0 +1,84 @@
+//! Solving days and printing their answers.
+
+use std::process::ExitCode;
+
+use aoc_core::{Day, Input, InputError, Registry};
+
+use crate::{
+    args::{InputSource, Run},
+    table::Table,
+};
+
+fn load(day: &Day, source: Option<&InputSource>) -> Result<Input, InputError> {
+    match source {
+        Some(InputSource::Stdin) => Input::from_stdin(),
+        Some(InputSource::Path(path)) => Input::from_path(path),
+        None => day.inputs().load(day.day),
+    }
+}
+
+pub fn run(registry: &Registry, args: &Run) -> ExitCode {
+    let days = registry
+        .days()
+        .filter(|day| day.year == args.year && args.days.contains(day.day))
+        .collect::<Vec<_>>();
+    if days.is_empty() {
+        eprintln!("error: no solution for {} {}", args.year, args.days);
+        return ExitCode::FAILURE;
+    }
+
+    let mut table = Table::new(&["year", "day", "part", "answer"]);
+    let mut failed = false;
+    for day in days {
+        let input = match load(day, args.input.as_ref()) {
+            Ok(input) => input,
+            Err(err) => {
+                eprintln!("error: {err}");
+                failed = true;
+                continue;
+            }
+        };
+
+        let parsed = match day.parse(&input) {
+            Ok(parsed) => parsed,
+            Err(err) => {
+                eprintln!(
+                    "error: {} day {}: {}",
+                    day.year,
+                    day.day,
+                    err.diagnostic(&input)
+                );
+                failed = true;
+                continue;
+            }
+        };
+        for &part in args.parts() {
+            match day.solve(&parsed, part) {
+                Ok(answer) => table.push(vec![
+                    day.year.to_string(),
+                    day.day.to_string(),
+                    part.to_string(),
+                    answer.to_string(),
+                ]),
+                Err(err) => {
+                    eprintln!(
+                        "error: {} day {} part {part}: {}",
+                        day.year,
+                        day.day,
+                        err.diagnostic(&input)
+                    );
+                    failed = true;
+                }
+            }
+        }
+    }
+
+    if !table.is_empty() {
+        print!("{table}");
+    }
+    if failed {
+        ExitCode::FAILURE
+    } else {
+        ExitCode::SUCCESS
+    }
+}
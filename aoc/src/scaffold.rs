@@ -0,0 +1,331 @@
+//! Generating the module, registration, input and example of a new day.
+
+use std::{
+    fmt, fs, io,
+    path::{Path, PathBuf},
+    process::ExitCode,
+};
+
+use aoc_core::InputLoader;
+
+use crate::args::New;
+
+/// Why a day could not be scaffolded.
+#[derive(Debug)]
+pub enum ScaffoldError {
+    UnknownYear(u16),
+    /// The day already has a module, which is never overwritten.
+    DayExists(PathBuf),
+    /// The crate root already declares the day's module, though its file is missing.
+    AlreadyWired {
+        day: u8,
+        lib: PathBuf,
+    },
+    /// The crate root has no place to declare or register the day.
+    NoPlaceFor {
+        what: &'static str,
+        path: PathBuf,
+    },
+    Io {
+        path: PathBuf,
+        source: io::Error,
+    },
+}
+
+impl fmt::Display for ScaffoldError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::UnknownYear(year) => write!(f, "there is no crate for {year}"),
+            Self::DayExists(path) => write!(f, "{} already exists", path.display()),
+            Self::AlreadyWired { day, lib } => {
+                write!(
+                    f,
+                    "module day{day} is already declared in {}",
+                    lib.display()
+                )
+            }
+            Self::NoPlaceFor { what, path } => {
+                write!(f, "found nowhere to {what} in {}", path.display())
+            }
+            Self::Io { path, source } => write!(f, "{}: {source}", path.display()),
+        }
+    }
+}
+
+fn io_error(path: &Path) -> impl FnOnce(io::Error) -> ScaffoldError + '_ {
+    |source| ScaffoldError::Io {
+        path: path.to_path_buf(),
+        source,
+    }
+}
+
+fn module(day: u8) -> String {
+    format!(
+        r#"//! Day {day}
+
+use aoc_core::{{Answer, Input, ParseError, Solution}};
+
+pub struct Day{day};
+
+impl Solution for Day{day} {{
+    type Parsed = Vec<String>;
+
+    fn parse(input: &Input) -> Result<Self::Parsed, ParseError> {{
+        Ok(input.lines().map(str::to_string).collect())
+    }}
+
+    fn part_one(_lines: &Self::Parsed) -> Result<Answer, ParseError> {{
+        Ok(Answer::Unsolved)
+    }}
+
+    fn part_two(_lines: &Self::Parsed) -> Result<Answer, ParseError> {{
+        Ok(Answer::Unsolved)
+    }}
+}}
+
+#[cfg(test)]
+mod tests {{
+    use super::*;
+    use crate::INPUTS;
+
+    #[test]
+    fn test_example1() {{
+        let lines = Day{day}::parse(&INPUTS.example({day}, 1)).unwrap();
+        assert_eq!(Day{day}::part_one(&lines), Ok(Answer::Unsolved));
+        assert_eq!(Day{day}::part_two(&lines), Ok(Answer::Unsolved));
+    }}
+
+    #[test]
+    fn test_input() {{
+        let Some(input) = INPUTS.input_if_present({day}) else {{
+            return;
+        }};
+        let lines = Day{day}::parse(&input).unwrap();
+        assert_eq!(Day{day}::part_one(&lines), Ok(Answer::Unsolved));
+        assert_eq!(Day{day}::part_two(&lines), Ok(Answer::Unsolved));
+    }}
+}}
+"#
+    )
+}
+
+/// Insert `line` among the lines that `day_of` finds a day number in, keeping them ordered by
+/// day. When there are none yet, `line` goes right after the first line `fallback` accepts.
+fn insert_ordered(
+    lines: &mut Vec<String>,
+    line: String,
+    day: u8,
+    day_of: impl Fn(&str) -> Option<u8>,
+    fallback: impl Fn(&str) -> bool,
+) -> bool {
+    let days = lines
+        .iter()
+        .enumerate()
+        .filter_map(|(index, line)| Some((index, day_of(line.trim())?)))
+        .collect::<Vec<_>>();
+
+    let index = match days.iter().find(|&&(_, other)| other > day) {
+        Some(&(index, _)) => index,
+        None => match days.last() {
+            Some(&(index, _)) => index + 1,
+            None => match lines.iter().position(|line| fallback(line)) {
+                Some(index) => index + 1,
+                None => return false,
+            },
+        },
+    };
+    lines.insert(index, line);
+    true
+}
+
+/// Declare and register `day` in the source of a year's `lib.rs`.
+fn wire_up(lib: &str, lib_path: &Path, day: u8) -> Result<String, ScaffoldError> {
+    let mut lines = lib.lines().map(str::to_string).collect::<Vec<_>>();
+    if lines
+        .iter()
+        .any(|line| line.trim() == format!("pub mod day{day};"))
+    {
+        return Err(ScaffoldError::AlreadyWired {
+            day,
+            lib: lib_path.to_path_buf(),
+        });
+    }
+
+    let declared = insert_ordered(
+        &mut lines,
+        format!("pub mod day{day};"),
+        day,
+        |line| {
+            line.strip_prefix("pub mod day")?
+                .strip_suffix(';')?
+                .parse()
+                .ok()
+        },
+        |line| line.starts_with("pub use "),
+    );
+    if !declared {
+        return Err(ScaffoldError::NoPlaceFor {
+            what: "declare the module",
+            path: lib_path.to_path_buf(),
+        });
+    }
+
+    let registered = insert_ordered(
+        &mut lines,
+        format!("    registry.register::<day{day}::Day{day}>(&INPUTS, {day});"),
+        day,
+        |line| {
+            let (_, day) = line
+                .strip_prefix("registry.register::<day")?
+                .strip_suffix(");")?
+                .rsplit_once(", ")?;
+            day.parse().ok()
+        },
+        |line| line.starts_with("pub fn register("),
+    );
+    if !registered {
+        return Err(ScaffoldError::NoPlaceFor {
+            what: "register the day",
+            path: lib_path.to_path_buf(),
+        });
+    }
+
+    Ok(lines.join("\n") + "\n")
+}
+
+/// Create everything a new day needs in the crate at `crate_dir`, returning the paths it created
+/// or changed. Refuses to touch anything when the day already has a module.
+pub fn scaffold(
+    crate_dir: &Path,
+    inputs: &InputLoader,
+    day: u8,
+) -> Result<Vec<PathBuf>, ScaffoldError> {
+    let module_path = crate_dir.join(format!("src/day{day}.rs"));
+    if module_path.exists() {
+        return Err(ScaffoldError::DayExists(module_path));
+    }
+    let lib_path = crate_dir.join("src/lib.rs");
+    let lib = fs::read_to_string(&lib_path).map_err(io_error(&lib_path))?;
+    let lib = wire_up(&lib, &lib_path, day)?;
+
+    fs::write(&module_path, module(day)).map_err(io_error(&module_path))?;
+    fs::write(&lib_path, lib).map_err(io_error(&lib_path))?;
+    let mut changed = vec![module_path, lib_path];
+
+    // Inputs may have been saved before the day was started, keep those.
+    for path in [inputs.path(day), inputs.example_path(day, 1)] {
+        if path.exists() {
+            continue;
+        }
+        if let Some(dir) = path.parent() {
+            fs::create_dir_all(dir).map_err(io_error(dir))?;
+        }
+        fs::write(&path, "").map_err(io_error(&path))?;
+        changed.push(path);
+    }
+
+    Ok(changed)
+}
+
+pub fn new(years: &[&InputLoader], args: &New) -> ExitCode {
+    let result = years
+        .iter()
+        .find(|inputs| inputs.year() == args.year)
+        .ok_or(ScaffoldError::UnknownYear(args.year))
+        .and_then(|inputs| scaffold(inputs.crate_dir(), inputs, args.day));
+
+    match result {
+        Ok(paths) => {
+            for path in paths {
+                println!("wrote {}", path.display());
+            }
+            ExitCode::SUCCESS
+        }
+        Err(err) => {
+            eprintln!("error: {err}");
+            ExitCode::FAILURE
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const LIB: &str = "\
+//! Advent of Code 2000.
+
+pub use aoc_core::{Input, InputError};
+
+pub mod day1;
+pub mod day3;
+
+pub fn register(registry: &mut Registry) {
+    registry.register::<day1::Day1>(&INPUTS, 1);
+    registry.register::<day3::Day3>(&INPUTS, 3);
+}
+";
+
+    #[test]
+    fn test_wire_up() {
+        let lib = wire_up(LIB, Path::new("lib.rs"), 2).unwrap();
+        assert!(lib.contains("pub mod day1;\npub mod day2;\npub mod day3;\n"));
+        assert!(lib.contains(
+            "(&INPUTS, 1);\n    registry.register::<day2::Day2>(&INPUTS, 2);\n    registry"
+        ));
+
+        let lib = wire_up(LIB, Path::new("lib.rs"), 25).unwrap();
+        assert!(lib.contains("pub mod day3;\npub mod day25;\n"));
+        assert!(lib.contains("(&INPUTS, 25);\n}\n"));
+
+        let err = wire_up(LIB, Path::new("lib.rs"), 3).unwrap_err();
+        assert!(matches!(err, ScaffoldError::AlreadyWired { day: 3, .. }));
+        assert_eq!(err.to_string(), "module day3 is already declared in lib.rs");
+    }
+
+    #[test]
+    fn test_wire_up_first_day() {
+        let lib = "pub use aoc_core::Input;\n\npub fn register(registry: &mut Registry) {\n}\n";
+        assert_eq!(
+            wire_up(lib, Path::new("lib.rs"), 1).unwrap(),
+            "pub use aoc_core::Input;\npub mod day1;\n\npub fn register(registry: &mut Registry) {\n    registry.register::<day1::Day1>(&INPUTS, 1);\n}\n"
+        );
+        assert!(matches!(
+            wire_up("", Path::new("lib.rs"), 1),
+            Err(ScaffoldError::NoPlaceFor { .. })
+        ));
+    }
+
+    #[test]
+    fn test_scaffold() {
+        let crate_dir = std::env::temp_dir().join("aoc-scaffold-test");
+        let _ = fs::remove_dir_all(&crate_dir);
+        fs::create_dir_all(crate_dir.join("src")).unwrap();
+        fs::write(crate_dir.join("src/lib.rs"), LIB).unwrap();
+        let input_dir = crate_dir.join("input");
+        let inputs = InputLoader::new(2000, "")
+            .dir(&input_dir)
+            .example_dir(&input_dir);
+
+        let paths = scaffold(&crate_dir, &inputs, 2).unwrap();
+        assert_eq!(
+            paths,
+            [
+                crate_dir.join("src/day2.rs"),
+                crate_dir.join("src/lib.rs"),
+                input_dir.join("day2.txt"),
+                input_dir.join("day2-example1.txt"),
+            ]
+        );
+        let module = fs::read_to_string(&paths[0]).unwrap();
+        assert!(module.starts_with("//! Day 2\n"));
+        assert!(module.contains("impl Solution for Day2"));
+
+        let lib = fs::read_to_string(&paths[1]).unwrap();
+        assert!(matches!(
+            scaffold(&crate_dir, &inputs, 2),
+            Err(ScaffoldError::DayExists(_))
+        ));
+        assert_eq!(fs::read_to_string(&paths[1]).unwrap(), lib);
+    }
+}
@@ -0,0 +1,134 @@
+//! Timing parsing and each part of days on their real input.
+
+use std::process::ExitCode;
+
+use aoc_core::{measure, time_runs, Allocations, Bytes, Elapsed, Part, Registry, Step, Timings};
+
+use crate::{args::Time, table::Table};
+
+/// Whether the runner counts allocations, which only works with its counting allocator installed.
+const COUNT_ALLOCATIONS: bool = cfg!(feature = "count-allocations");
+
+/// The allocations of a run of `f` of its own, so counting doesn't skew the timings. Nothing runs
+/// when allocations aren't counted.
+fn count_allocations<T>(f: impl FnOnce() -> T) -> Allocations {
+    if COUNT_ALLOCATIONS {
+        measure(f).1
+    } else {
+        Allocations::default()
+    }
+}
+
+fn row(
+    year: u16,
+    day: &str,
+    step: &str,
+    timings: Timings,
+    allocations: Allocations,
+) -> Vec<String> {
+    let mut row = vec![
+        year.to_string(),
+        day.to_string(),
+        step.to_string(),
+        Elapsed(timings.min).to_string(),
+        Elapsed(timings.median).to_string(),
+        Elapsed(timings.max).to_string(),
+    ];
+    if COUNT_ALLOCATIONS {
+        row.extend([
+            allocations.count.to_string(),
+            Bytes(allocations.bytes).to_string(),
+            Bytes(allocations.peak).to_string(),
+        ]);
+    }
+    row
+}
+
+pub fn time(registry: &Registry, args: &Time) -> ExitCode {
+    let days = registry
+        .days()
+        .filter(|day| args.selection.includes(day.year, day.day))
+        .collect::<Vec<_>>();
+    if days.is_empty() {
+        eprintln!("error: no solution for {}", args.selection.days);
+        return ExitCode::FAILURE;
+    }
+
+    let mut columns = vec!["year", "day", "step", "min", "median", "max"];
+    if COUNT_ALLOCATIONS {
+        columns.extend(["allocs", "bytes", "peak"]);
+    }
+    let mut table = Table::new(&columns);
+    let mut failed = false;
+    for days in days.chunk_by(|a, b| a.year == b.year) {
+        let mut steps = Vec::new();
+        for day in days {
+            let input = match day.inputs().load(day.day) {
+                Ok(input) => input,
+                Err(err) => {
+                    eprintln!("error: {err}");
+                    failed = true;
+                    continue;
+                }
+            };
+
+            let (parsed, timings) = time_runs(args.runs, || day.parse(&input));
+            let parsed = match parsed {
+                Ok(parsed) => parsed,
+                Err(err) => {
+                    eprintln!(
+                        "error: {} day {}: {}",
+                        day.year,
+                        day.day,
+                        err.diagnostic(&input)
+                    );
+                    failed = true;
+                    continue;
+                }
+            };
+            let allocations = count_allocations(|| day.parse(&input));
+            let day_name = day.day.to_string();
+            let step = Step::Parse.to_string();
+            table.push(row(day.year, &day_name, &step, timings, allocations));
+            steps.push((timings, allocations));
+
+            for part in Part::ALL {
+                let (answer, timings) = time_runs(args.runs, || day.solve(&parsed, part));
+                if let Err(err) = answer {
+                    eprintln!(
+                        "error: {} day {} part {part}: {}",
+                        day.year,
+                        day.day,
+                        err.diagnostic(&input)
+                    );
+                    failed = true;
+                    continue;
+                }
+                let allocations = count_allocations(|| day.solve(&parsed, part));
+                let step = Step::Part(part).to_string();
+                table.push(row(day.year, &day_name, &step, timings, allocations));
+                steps.push((timings, allocations));
+            }
+        }
+
+        if !steps.is_empty() {
+            let (timings, allocations) = steps.into_iter().unzip::<_, _, Vec<_>, Vec<_>>();
+            table.push(row(
+                days[0].year,
+                "total",
+                "",
+                timings.into_iter().sum(),
+                allocations.into_iter().sum(),
+            ));
+        }
+    }
+
+    if !table.is_empty() {
+        print!("{table}");
+    }
+    if failed {
+        ExitCode::FAILURE
+    } else {
+        ExitCode::SUCCESS
+    }
+}
@@ -0,0 +1,123 @@
+//! Checking days against the confirmed answers of their year.
+
+use std::{fmt, process::ExitCode};
+
+use aoc_core::{Answers, Registry, Status};
+
+use crate::{args::Verify, table::Table};
+
+/// How many parts ended up with each status.
+#[derive(Debug, Default)]
+struct Summary {
+    pass: usize,
+    fail: usize,
+    skipped: usize,
+    unknown: usize,
+    regression: usize,
+}
+
+impl Summary {
+    fn add(&mut self, status: &Status) {
+        let count = match status {
+            Status::Pass => &mut self.pass,
+            Status::Fail => &mut self.fail,
+            Status::Skipped => &mut self.skipped,
+            Status::Unknown => &mut self.unknown,
+            Status::Regression { .. } => &mut self.regression,
+        };
+        *count += 1;
+    }
+}
+
+impl fmt::Display for Summary {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "{} pass, {} fail, {} skipped, {} unknown, {} regression",
+            self.pass, self.fail, self.skipped, self.unknown, self.regression
+        )
+    }
+}
+
+pub fn verify(registry: &Registry, args: &Verify) -> ExitCode {
+    let days = registry
+        .days()
+        .filter(|day| args.selection.includes(day.year, day.day))
+        .collect::<Vec<_>>();
+    if days.is_empty() {
+        eprintln!("error: no solution for {}", args.selection.days);
+        return ExitCode::FAILURE;
+    }
+
+    let mut table = Table::new(&["year", "day", "part", "answer", "expected", "status"]);
+    let mut errors = Vec::new();
+    let mut summary = Summary::default();
+    let mut failed = false;
+    for days in days.chunk_by(|a, b| a.year == b.year) {
+        let path = days[0].inputs().answers_path();
+        let mut answers = match Answers::load(&path) {
+            Ok(answers) => answers,
+            Err(err) => {
+                eprintln!("error: {err}");
+                failed = true;
+                continue;
+            }
+        };
+
+        let mut recorded = 0;
+        for day in days {
+            for check in aoc_core::verify(day, &answers) {
+                let answer = match &check.answer {
+                    Ok(answer) => answer.to_string(),
+                    Err(_) if check.status == Status::Skipped => "-".to_string(),
+                    Err(err) => {
+                        let error = format!("{} day {}: {err}", check.year, check.day);
+                        if !errors.contains(&error) {
+                            errors.push(error);
+                        }
+                        "-".to_string()
+                    }
+                };
+                let expected = check
+                    .expected
+                    .as_ref()
+                    .map_or("-".to_string(), ToString::to_string);
+                table.push(vec![
+                    check.year.to_string(),
+                    check.day.to_string(),
+                    check.part.to_string(),
+                    answer,
+                    expected,
+                    check.status.to_string(),
+                ]);
+
+                summary.add(&check.status);
+                failed |= matches!(check.status, Status::Fail | Status::Regression { .. });
+                if args.record && answers.record(&check) {
+                    recorded += 1;
+                }
+            }
+        }
+
+        if recorded > 0 {
+            match answers.save(&path) {
+                Ok(()) => eprintln!("recorded {recorded} answers in {}", path.display()),
+                Err(err) => {
+                    eprintln!("error: failed to write {}: {err}", path.display());
+                    failed = true;
+                }
+            }
+        }
+    }
+
+    print!("{table}");
+    println!("{summary}");
+    for error in errors {
+        eprintln!("error: {error}");
+    }
+    if failed {
+        ExitCode::FAILURE
+    } else {
+        ExitCode::SUCCESS
+    }
+}